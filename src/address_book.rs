@@ -0,0 +1,147 @@
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use colored::Colorize;
+use eyre::eyre;
+use lazy_static::lazy_static;
+use serde_json::{Map, Value, json};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::output;
+use crate::squads_decoder::BUILTIN_PROGRAMS;
+
+/// Overrides the location of the address book.
+pub const ADDRESS_BOOK_ENV: &str = "SECURE_SQUADS_ADDRESS_BOOK";
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AddressBookEntry {
+    pub label: String,
+    pub category: Option<String>,
+}
+
+impl std::fmt::Display for AddressBookEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.category {
+            Some(category) => write!(f, "{} [{}]", self.label, category),
+            None => write!(f, "{}", self.label),
+        }
+    }
+}
+
+/// Known addresses of the team, a JSON object keyed by pubkey:
+/// `{"<pubkey>": {"label": "Exchange deposit", "category": "exchange"}}`.
+#[derive(Clone, Debug, Default)]
+pub struct AddressBook {
+    pub entries: BTreeMap<Pubkey, AddressBookEntry>,
+}
+
+impl AddressBook {
+    /// `$SECURE_SQUADS_ADDRESS_BOOK`, or `~/.config/secure-squads/address-book.json`.
+    pub fn default_path() -> PathBuf {
+        if let Some(path) = std::env::var_os(ADDRESS_BOOK_ENV) {
+            return PathBuf::from(path);
+        }
+        let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+        PathBuf::from(home)
+            .join(".config")
+            .join("secure-squads")
+            .join("address-book.json")
+    }
+
+    /// Loads the address book, a missing file is an empty book.
+    pub fn load(path: &Path) -> eyre::Result<Self> {
+        let contents = match std::fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(eyre!("Failed to read {}: {}", path.display(), e)),
+        };
+        let document: Map<String, Value> = serde_json::from_str(&contents)
+            .map_err(|e| eyre!("{} is not a valid address book: {}", path.display(), e))?;
+
+        let mut entries = BTreeMap::new();
+        for (address, entry) in document {
+            let pubkey = Pubkey::from_str(&address)
+                .map_err(|_| eyre!("Invalid address {} in {}", address, path.display()))?;
+            let label = entry["label"]
+                .as_str()
+                .ok_or_else(|| eyre!("{} has no label in {}", address, path.display()))?;
+            entries.insert(
+                pubkey,
+                AddressBookEntry {
+                    label: label.to_string(),
+                    category: entry["category"].as_str().map(str::to_string),
+                },
+            );
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, path: &Path) -> eyre::Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let document: Map<String, Value> = self
+            .entries
+            .iter()
+            .map(|(pubkey, entry)| (pubkey.to_string(), entry_json(entry)))
+            .collect();
+        std::fs::write(path, serde_json::to_string_pretty(&document)? + "\n")
+            .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, pubkey: &Pubkey) -> Option<&AddressBookEntry> {
+        self.entries.get(pubkey)
+    }
+}
+
+pub fn entry_json(entry: &AddressBookEntry) -> Value {
+    json!({
+        "label": entry.label,
+        "category": entry.category,
+    })
+}
+
+lazy_static! {
+    /// The book every command labels addresses with, loaded once.
+    static ref ADDRESS_BOOK: AddressBook = {
+        let path = AddressBook::default_path();
+        AddressBook::load(&path).unwrap_or_else(|e| {
+            eprintln!("{}", format!("⚠️ Address book not loaded: {}", e).yellow());
+            AddressBook::default()
+        })
+    };
+}
+
+/// The address book entry of `pubkey`, falling back to the builtin program names.
+pub fn label(pubkey: &Pubkey) -> Option<String> {
+    ADDRESS_BOOK
+        .get(pubkey)
+        .map(|entry| entry.to_string())
+        .or_else(|| BUILTIN_PROGRAMS.get(pubkey).map(|name| name.to_string()))
+}
+
+/// `<pubkey> (<label>)`, or the bare pubkey for unknown addresses.
+pub fn describe(pubkey: &Pubkey) -> String {
+    match label(pubkey) {
+        Some(label) => format!("{} ({})", pubkey, label),
+        None => pubkey.to_string(),
+    }
+}
+
+pub fn is_known(pubkey: &Pubkey) -> bool {
+    ADDRESS_BOOK.get(pubkey).is_some()
+}
+
+/// Funds sent to an address nobody recorded deserve a second look at the recipient.
+pub fn warn_unknown_recipient(recipient: &Pubkey) {
+    if is_known(recipient) {
+        return;
+    }
+    let warning = format!(
+        "{} is not in the address book, verify the recipient out of band.",
+        recipient
+    );
+    println!("{}", format!("⚠️ WARNING: {}", warning).red());
+    output::push("warnings", json!(warning));
+}
@@ -0,0 +1,168 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::{Value, json};
+use solana_sdk::pubkey::Pubkey;
+
+use crate::address_book::{AddressBook as Book, AddressBookEntry, entry_json};
+use crate::output;
+
+/// Labels for the addresses the team sends to, shown next to them in every review
+#[derive(Args)]
+pub struct AddressBook {
+    #[command(subcommand)]
+    command: AddressBookCommand,
+}
+
+#[derive(Subcommand)]
+pub enum AddressBookCommand {
+    Add(AddressBookAdd),
+    Remove(AddressBookRemove),
+    List(AddressBookList),
+}
+
+impl AddressBook {
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            AddressBookCommand::Add(command) => command.execute(),
+            AddressBookCommand::Remove(command) => command.execute(),
+            AddressBookCommand::List(command) => command.execute(),
+        }
+    }
+}
+
+fn book_path(path: Option<String>) -> PathBuf {
+    path.map(PathBuf::from).unwrap_or_else(Book::default_path)
+}
+
+#[derive(Args)]
+pub struct AddressBookAdd {
+    /// Path of the address book, defaults to $SECURE_SQUADS_ADDRESS_BOOK or ~/.config/secure-squads/address-book.json
+    #[arg(long)]
+    path: Option<String>,
+
+    /// The address to label
+    #[arg(long)]
+    address: String,
+
+    /// Human readable name, e.g. "Treasury cold wallet"
+    #[arg(long)]
+    label: String,
+
+    /// Optional grouping, e.g. exchange, team, protocol
+    #[arg(long)]
+    category: Option<String>,
+}
+
+impl AddressBookAdd {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self {
+            path,
+            address,
+            label,
+            category,
+        } = self;
+
+        let path = book_path(path);
+        let address = Pubkey::from_str(&address).expect("Invalid address");
+        if label.trim().is_empty() {
+            return Err(eyre!("The label must not be empty"));
+        }
+
+        let mut book = Book::load(&path)?;
+        let entry = AddressBookEntry {
+            label: label.trim().to_string(),
+            category,
+        };
+        if let Some(previous) = book.entries.insert(address, entry.clone()) {
+            println!(
+                "{}",
+                format!("Replacing the previous label: {}", previous).yellow()
+            );
+        }
+        book.save(&path)?;
+
+        output::set("address", json!(address.to_string()));
+        output::set("entry", entry_json(&entry));
+        println!(
+            "{}",
+            format!("✅ Added {}: {} to {}", address, entry, path.display()).green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct AddressBookRemove {
+    /// Path of the address book, defaults to $SECURE_SQUADS_ADDRESS_BOOK or ~/.config/secure-squads/address-book.json
+    #[arg(long)]
+    path: Option<String>,
+
+    /// The address to forget
+    #[arg(long)]
+    address: String,
+}
+
+impl AddressBookRemove {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self { path, address } = self;
+
+        let path = book_path(path);
+        let address = Pubkey::from_str(&address).expect("Invalid address");
+
+        let mut book = Book::load(&path)?;
+        let entry = book
+            .entries
+            .remove(&address)
+            .ok_or_else(|| eyre!("{} is not in the address book", address))?;
+        book.save(&path)?;
+
+        output::set("address", json!(address.to_string()));
+        output::set("entry", entry_json(&entry));
+        println!(
+            "{}",
+            format!("✅ Removed {}: {} from {}", address, entry, path.display()).green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct AddressBookList {
+    /// Path of the address book, defaults to $SECURE_SQUADS_ADDRESS_BOOK or ~/.config/secure-squads/address-book.json
+    #[arg(long)]
+    path: Option<String>,
+
+    /// Only list the entries of this category
+    #[arg(long)]
+    category: Option<String>,
+}
+
+impl AddressBookList {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self { path, category } = self;
+
+        let path = book_path(path);
+        let book = Book::load(&path)?;
+
+        println!("Address Book -> {}", path.display());
+        let mut entries = Vec::new();
+        for (address, entry) in &book.entries {
+            if category.is_some() && entry.category != category {
+                continue;
+            }
+            println!("  {}  {}", address, entry.to_string().bright_cyan());
+            let mut value = entry_json(entry);
+            value["address"] = Value::String(address.to_string());
+            entries.push(value);
+        }
+        if entries.is_empty() {
+            println!("No entries found.");
+        }
+        output::set("entries", Value::Array(entries));
+        Ok(())
+    }
+}
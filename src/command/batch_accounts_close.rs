@@ -0,0 +1,250 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::{AccountDeserialize, InstructionData};
+use squads_multisig::client::get_multisig;
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::accounts::BatchAccountsClose as BatchAccountsCloseAccounts;
+use squads_multisig::squads_multisig_program::accounts::VaultBatchTransactionAccountClose as VaultBatchTransactionAccountCloseAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::BatchAccountsClose as BatchAccountsCloseData;
+use squads_multisig::squads_multisig_program::instruction::VaultBatchTransactionAccountClose as VaultBatchTransactionAccountCloseData;
+use squads_multisig::state::{Batch, Proposal, ProposalStatus};
+
+use crate::utils::{
+    create_signer_from_path, get_batch_transaction_pda, send_and_confirm_transaction,
+    transaction_details,
+};
+
+/// Close instructions packed into a single transaction.
+const CLOSES_PER_TRANSACTION: usize = 8;
+
+#[derive(Args)]
+pub struct BatchAccountsClose {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair paying the transaction fees
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig owning the batch
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Transaction index of the batch
+    #[arg(long)]
+    batch_index: u64,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl BatchAccountsClose {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            batch_index,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let fee_payer_keypair = create_signer_from_path(keypair).unwrap();
+
+        let fee_payer = fee_payer_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        let rent_collector = multisig_data.rent_collector.ok_or_else(|| {
+            eyre!("The multisig has no rent collector, batch accounts cannot be closed")
+        })?;
+
+        let batch_pda = get_transaction_pda(&multisig, batch_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig, batch_index, Some(&program_id));
+
+        let batch_account = rpc_client
+            .get_account(&batch_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get batch account: {}", e))?;
+        let batch = Batch::try_deserialize(&mut batch_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a batch account", batch_pda.0))?;
+        let proposal_account = rpc_client
+            .get_account(&proposal_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get proposal account: {}", e))?;
+        let proposal = Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a proposal account", proposal_pda.0))?;
+
+        let is_stale = batch_index <= multisig_data.stale_transaction_index;
+        let can_close = match proposal.status {
+            ProposalStatus::Draft { .. } | ProposalStatus::Active { .. } => is_stale,
+            ProposalStatus::Rejected { .. }
+            | ProposalStatus::Executed { .. }
+            | ProposalStatus::Cancelled { .. } => true,
+            _ => false,
+        };
+        if !can_close {
+            return Err(eyre!(
+                "A batch with proposal status {:?} cannot be closed{}",
+                proposal.status,
+                if is_stale { "" } else { " (not stale)" }
+            ));
+        }
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to close a batch and its transactions, please review the details:"
+                .yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", fee_payer);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Batch:       {}", batch_pda.0);
+        println!("Batch Index:       {}", batch_index);
+        println!("Proposal Status:       {:?}", proposal.status);
+        println!("Batch Transactions:       {}", batch.size);
+        println!("Rent Collector:       {}", rent_collector);
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let close_instructions = batch_close_instructions(
+            &multisig,
+            batch_index,
+            batch.size,
+            &rent_collector,
+            &program_id,
+        );
+        let chunks: Vec<&[Instruction]> =
+            close_instructions.chunks(CLOSES_PER_TRANSACTION).collect();
+        for (i, chunk) in chunks.iter().enumerate() {
+            let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+            progress.enable_steady_tick(Duration::from_millis(100));
+
+            let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+                priority_fee_lamports.unwrap_or(5000),
+            )];
+            instructions.extend_from_slice(chunk);
+
+            let blockhash = rpc_client
+                .get_latest_blockhash()
+                .await
+                .expect("Failed to get blockhash");
+
+            let message = Message::try_compile(&fee_payer, &instructions, &[], blockhash).unwrap();
+
+            let transaction = VersionedTransaction::try_new(
+                VersionedMessage::V0(message),
+                &[&*fee_payer_keypair],
+            )
+            .expect("Failed to create transaction");
+            if let Err(e) = transaction_details(&transaction) {
+                eprintln!("❌ Error processing transaction details: {}", e);
+            }
+
+            let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+            progress.finish_and_clear();
+
+            println!(
+                "✅ Sent close transaction {} of {}. Signature: {}",
+                i + 1,
+                chunks.len(),
+                signature.green()
+            );
+        }
+        println!("✅ Closed Batch {} and its proposal.", batch_pda.0);
+
+        Ok(())
+    }
+}
+
+/// Instructions closing every transaction of a batch and then the batch and its proposal.
+/// The program only closes the last transaction of a batch, so they are walked backwards.
+pub fn batch_close_instructions(
+    multisig: &Pubkey,
+    batch_index: u64,
+    batch_size: u32,
+    rent_collector: &Pubkey,
+    program_id: &Pubkey,
+) -> Vec<Instruction> {
+    let batch_pda = get_transaction_pda(multisig, batch_index, Some(program_id));
+    let proposal_pda = get_proposal_pda(multisig, batch_index, Some(program_id));
+
+    let mut instructions: Vec<Instruction> = (1..=batch_size)
+        .rev()
+        .map(|step_index| {
+            let batch_transaction_pda =
+                get_batch_transaction_pda(multisig, batch_index, step_index, Some(program_id));
+            Instruction {
+                accounts: VaultBatchTransactionAccountCloseAccounts {
+                    multisig: *multisig,
+                    proposal: proposal_pda.0,
+                    batch: batch_pda.0,
+                    transaction: batch_transaction_pda.0,
+                    rent_collector: *rent_collector,
+                    system_program: system_program::id(),
+                }
+                .to_account_metas(Some(false)),
+                data: VaultBatchTransactionAccountCloseData {}.data(),
+                program_id: *program_id,
+            }
+        })
+        .collect();
+    instructions.push(Instruction {
+        accounts: BatchAccountsCloseAccounts {
+            multisig: *multisig,
+            proposal: proposal_pda.0,
+            batch: batch_pda.0,
+            rent_collector: *rent_collector,
+            system_program: system_program::id(),
+        }
+        .to_account_metas(Some(false)),
+        data: BatchAccountsCloseData {}.data(),
+        program_id: *program_id,
+    });
+    instructions
+}
@@ -0,0 +1,372 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_program::bpf_loader_upgradeable::upgrade;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use squads_multisig::anchor_lang::{AccountDeserialize, AnchorSerialize, InstructionData};
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::BatchAddTransactionArgs;
+use squads_multisig::squads_multisig_program::TransactionMessage;
+use squads_multisig::squads_multisig_program::accounts::BatchAddTransaction as BatchAddTransactionAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalActivate as ProposalActivateAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::BatchAddTransaction as BatchAddTransactionData;
+use squads_multisig::squads_multisig_program::instruction::ProposalActivate as ProposalActivateData;
+use squads_multisig::state::{Batch, Proposal, ProposalStatus};
+use squads_multisig::vault_transaction::VaultTransactionMessageExt;
+
+use crate::address_book::{describe, warn_unknown_recipient};
+use crate::token_amount::{fetch_mint_info, parse_ui_amount, transfer_checked_instruction};
+use crate::utils::{
+    create_signer_from_path, get_batch_transaction_pda, send_and_confirm_transaction,
+    transaction_details,
+};
+
+#[derive(Args)]
+pub struct BatchAddTransaction {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of the batch creator
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig owning the batch
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Transaction index of the batch
+    #[arg(long)]
+    batch_index: u64,
+
+    /// Token program ID. Defaults to the owner of the mint.
+    #[arg(long)]
+    token_program_id: Option<String>,
+
+    /// Token Mint Address of a transfer step
+    #[arg(long, requires = "recipient", conflicts_with = "program_to_upgrade_id")]
+    token_mint_address: Option<String>,
+
+    /// Amount in base units of the mint
+    #[arg(long, requires = "token_mint_address", conflicts_with = "amount")]
+    token_amount_u64: Option<u64>,
+
+    /// Amount in tokens, e.g. 12.5, converted with the decimals of the mint
+    #[arg(long, requires = "token_mint_address")]
+    amount: Option<String>,
+
+    /// The recipient of the Token(s)
+    #[arg(long)]
+    recipient: Option<String>,
+
+    /// The program to upgrade in a program upgrade step
+    #[arg(long, requires_all = ["buffer_address", "spill_address"])]
+    program_to_upgrade_id: Option<String>,
+
+    /// The buffer holding the new program data
+    #[arg(long)]
+    buffer_address: Option<String>,
+
+    /// The account that receives the excess lamports of the buffer
+    #[arg(long)]
+    spill_address: Option<String>,
+
+    /// Move the proposal from Draft to Active after adding this last step
+    #[arg(long, default_value_t = false)]
+    activate: bool,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl BatchAddTransaction {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            batch_index,
+            token_program_id,
+            token_mint_address,
+            token_amount_u64,
+            amount,
+            recipient,
+            program_to_upgrade_id,
+            buffer_address,
+            spill_address,
+            activate,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
+
+        let transaction_creator = transaction_creator_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let batch_pda = get_transaction_pda(&multisig, batch_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig, batch_index, Some(&program_id));
+
+        let batch_account = rpc_client
+            .get_account(&batch_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get batch account: {}", e))?;
+        let batch = Batch::try_deserialize(&mut batch_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a batch account", batch_pda.0))?;
+        let proposal_account = rpc_client
+            .get_account(&proposal_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get proposal account: {}", e))?;
+        let proposal = Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a proposal account", proposal_pda.0))?;
+
+        if batch.creator != transaction_creator {
+            return Err(eyre!(
+                "Only the batch creator {} can add transactions",
+                batch.creator
+            ));
+        }
+        if !matches!(proposal.status, ProposalStatus::Draft { .. }) {
+            return Err(eyre!(
+                "Transactions can only be added while the proposal is a Draft, it is {:?}",
+                proposal.status
+            ));
+        }
+
+        let vault_pda = get_vault_pda(&multisig, batch.vault_index, Some(&program_id));
+        let step_index = batch.size + 1;
+        let batch_transaction_pda =
+            get_batch_transaction_pda(&multisig, batch_index, step_index, Some(&program_id));
+
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee_lamports.unwrap_or(5000),
+        )];
+
+        let (step_description, step_instruction, recipient) = match (
+            token_mint_address,
+            program_to_upgrade_id,
+        ) {
+            (Some(token_mint_address), None) => {
+                let token_mint =
+                    Pubkey::from_str(&token_mint_address).expect("Invalid Token Mint Address");
+                let recipient_pubkey =
+                    Pubkey::from_str(&recipient.unwrap()).expect("Invalid recipient address");
+                let mint_info = fetch_mint_info(&rpc_client, &token_mint).await?;
+                if let Some(expected) = token_program_id {
+                    let expected = Pubkey::from_str(&expected).expect("Invalid token program ID");
+                    if expected != mint_info.token_program {
+                        return Err(eyre!(
+                            "The mint is owned by {}, not by the token program {}",
+                            mint_info.token_program,
+                            expected
+                        ));
+                    }
+                }
+                let token_program_id = mint_info.token_program;
+                let token_amount = match (token_amount_u64, amount) {
+                    (Some(token_amount_u64), _) => token_amount_u64,
+                    (None, Some(amount)) => parse_ui_amount(&amount, mint_info.decimals)?,
+                    (None, None) => {
+                        return Err(eyre!("Either --amount or --token-amount-u64 is required"));
+                    }
+                };
+
+                let sender_ata = get_associated_token_address_with_program_id(
+                    &vault_pda.0,
+                    &token_mint,
+                    &token_program_id,
+                );
+                let recipient_ata = get_associated_token_address_with_program_id(
+                    &recipient_pubkey,
+                    &token_mint,
+                    &token_program_id,
+                );
+                // The recipient account must exist by the time the step executes.
+                if rpc_client.get_account(&recipient_ata).await.is_err() {
+                    instructions.push(create_associated_token_account_idempotent(
+                        &transaction_creator,
+                        &recipient_pubkey,
+                        &token_mint,
+                        &token_program_id,
+                    ));
+                }
+                (
+                    format!(
+                        "Transfer {} of mint {} to {}",
+                        mint_info.display(token_amount),
+                        token_mint,
+                        describe(&recipient_pubkey)
+                    ),
+                    transfer_checked_instruction(
+                        &mint_info,
+                        &sender_ata,
+                        &recipient_ata,
+                        &vault_pda.0,
+                        token_amount,
+                    ),
+                    Some(recipient_pubkey),
+                )
+            }
+            (None, Some(program_to_upgrade_id)) => {
+                let program_to_upgrade = Pubkey::from_str(&program_to_upgrade_id)
+                    .expect("Invalid to upgrade program ID");
+                let buffer_address =
+                    Pubkey::from_str(&buffer_address.unwrap()).expect("Invalid buffer address");
+                let spill_address =
+                    Pubkey::from_str(&spill_address.unwrap()).expect("Invalid spill address");
+                (
+                    format!(
+                        "Upgrade program {} from buffer {}",
+                        program_to_upgrade, buffer_address
+                    ),
+                    upgrade(
+                        &program_to_upgrade,
+                        &buffer_address,
+                        &vault_pda.0,
+                        &spill_address,
+                    ),
+                    None,
+                )
+            }
+            _ => {
+                return Err(eyre!(
+                    "Specify either a transfer (--token-mint-address) or a program upgrade (--program-to-upgrade-id)"
+                ));
+            }
+        };
+
+        let transaction_message =
+            TransactionMessage::try_compile(&vault_pda.0, &[step_instruction], &[]).unwrap();
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to add a transaction to a batch, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", transaction_creator);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Batch:       {}", batch_pda.0);
+        println!("Batch Index:       {}", batch_index);
+        println!("Vault:       {}", vault_pda.0);
+        println!("Step:       {} of {}", step_index, step_index);
+        println!("Batch Transaction:       {}", batch_transaction_pda.0);
+        println!("Action:       {}", step_description.bright_cyan());
+        if let Some(recipient) = recipient {
+            warn_unknown_recipient(&recipient);
+        }
+        if activate {
+            println!(
+                "{}",
+                "⚠️ The proposal will be activated, no further steps can be added.".yellow()
+            );
+        }
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        instructions.push(Instruction {
+            accounts: BatchAddTransactionAccounts {
+                multisig,
+                proposal: proposal_pda.0,
+                batch: batch_pda.0,
+                transaction: batch_transaction_pda.0,
+                member: transaction_creator,
+                rent_payer: transaction_creator,
+                system_program: solana_sdk::system_program::id(),
+            }
+            .to_account_metas(Some(false)),
+            data: BatchAddTransactionData {
+                args: BatchAddTransactionArgs {
+                    ephemeral_signers: 0,
+                    transaction_message: transaction_message.try_to_vec().unwrap(),
+                },
+            }
+            .data(),
+            program_id,
+        });
+        if activate {
+            instructions.push(Instruction {
+                accounts: ProposalActivateAccounts {
+                    multisig,
+                    member: transaction_creator,
+                    proposal: proposal_pda.0,
+                }
+                .to_account_metas(Some(false)),
+                data: ProposalActivateData {}.data(),
+                program_id,
+            });
+        }
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message =
+            Message::try_compile(&transaction_creator, &instructions, &[], blockhash).unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*transaction_creator_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Added step {} to Batch {}. Signature: {}",
+            step_index,
+            batch_pda.0,
+            signature.green()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::client::get_multisig;
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::BatchCreateArgs;
+use squads_multisig::squads_multisig_program::ProposalCreateArgs;
+use squads_multisig::squads_multisig_program::accounts::BatchCreate as BatchCreateAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalCreate as ProposalCreateAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::BatchCreate as BatchCreateData;
+use squads_multisig::squads_multisig_program::instruction::ProposalCreate as ProposalCreateData;
+
+use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
+
+#[derive(Args)]
+pub struct BatchCreate {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of the member creating the batch
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig to create the batch for
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Vault every transaction of the batch is executed from
+    #[arg(long)]
+    vault_index: u8,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl BatchCreate {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            vault_index,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
+
+        let transaction_creator = transaction_creator_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+
+        let batch_index = multisig_data.transaction_index + 1;
+
+        let batch_pda = get_transaction_pda(&multisig, batch_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig, batch_index, Some(&program_id));
+        let vault_pda = get_vault_pda(&multisig, vault_index, Some(&program_id));
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to create a batch, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", transaction_creator);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Batch Index:       {}", batch_index);
+        println!("Batch:       {}", batch_pda.0);
+        println!("Vault Index:       {}", vault_index);
+        println!("Vault:       {}", vault_pda.0);
+        println!(
+            "{}",
+            "ℹ️ The proposal is created as a Draft, add the steps with batch-add-transaction before voting opens."
+                .bright_black()
+        );
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &transaction_creator,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: BatchCreateAccounts {
+                        multisig,
+                        creator: transaction_creator,
+                        rent_payer: transaction_creator,
+                        batch: batch_pda.0,
+                        system_program: solana_sdk::system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: BatchCreateData {
+                        args: BatchCreateArgs { vault_index, memo },
+                    }
+                    .data(),
+                    program_id,
+                },
+                Instruction {
+                    accounts: ProposalCreateAccounts {
+                        creator: transaction_creator,
+                        rent_payer: transaction_creator,
+                        proposal: proposal_pda.0,
+                        multisig,
+                        system_program: solana_sdk::system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: ProposalCreateData {
+                        args: ProposalCreateArgs {
+                            // Transactions can only be added to a batch while its proposal is a draft.
+                            draft: true,
+                            transaction_index: batch_index,
+                        },
+                    }
+                    .data(),
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*transaction_creator_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Created Batch {} at index {}. Signature: {}",
+            batch_pda.0,
+            batch_index,
+            signature.green()
+        );
+        Ok(())
+    }
+}
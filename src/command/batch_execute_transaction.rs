@@ -0,0 +1,239 @@
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+use squads_multisig::anchor_lang::{AccountDeserialize, InstructionData};
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::accounts::BatchExecuteTransaction as BatchExecuteTransactionAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::BatchExecuteTransaction as BatchExecuteTransactionData;
+use squads_multisig::squads_multisig_program::state::VaultBatchTransaction;
+use squads_multisig::state::{Batch, Proposal, ProposalStatus};
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::command::display_transaction::{
+    load_message_idls, print_token_amounts, print_transaction_message,
+};
+use crate::command::vault_transaction_execute::message_to_execute_account_metas;
+use crate::utils::{
+    create_signer_from_path, get_batch_transaction_pda, send_and_confirm_transaction,
+    transaction_details,
+};
+
+#[derive(Args)]
+pub struct BatchExecuteTransaction {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of a member with the Execute permission
+    #[arg(long)]
+    keypair: String,
+
+    /// Transaction index of the batch
+    #[arg(long)]
+    batch_index: u64,
+
+    /// The multisig owning the batch
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+
+    #[arg(long)]
+    compute_unit_limit: Option<u32>,
+}
+
+impl BatchExecuteTransaction {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            batch_index,
+            multisig_pubkey,
+            priority_fee_lamports,
+            compute_unit_limit,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
+
+        let transaction_creator = transaction_creator_keypair.pubkey();
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let batch_pda = get_transaction_pda(&multisig, batch_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig, batch_index, Some(&program_id));
+
+        let batch_account = rpc_client
+            .get_account(&batch_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get batch account: {}", e))?;
+        let batch = Batch::try_deserialize(&mut batch_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a batch account", batch_pda.0))?;
+        let proposal_account = rpc_client
+            .get_account(&proposal_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get proposal account: {}", e))?;
+        let proposal = Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a proposal account", proposal_pda.0))?;
+
+        if batch.executed_transaction_index >= batch.size {
+            return Err(eyre!(
+                "All {} transactions of the batch have been executed",
+                batch.size
+            ));
+        }
+        if !matches!(proposal.status, ProposalStatus::Approved { .. }) {
+            return Err(eyre!(
+                "The batch proposal must be Approved to execute, it is {:?}",
+                proposal.status
+            ));
+        }
+
+        // The program only executes the transactions in order, one at a time.
+        let step_index = batch.executed_transaction_index + 1;
+        let batch_transaction_pda =
+            get_batch_transaction_pda(&multisig, batch_index, step_index, Some(&program_id));
+        let batch_transaction_account = rpc_client
+            .get_account(&batch_transaction_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get batch transaction account: {}", e))?;
+        let batch_transaction =
+            VaultBatchTransaction::try_deserialize(&mut batch_transaction_account.data.as_slice())
+                .map_err(|_| {
+                    eyre!(
+                        "{} is not a batch transaction account",
+                        batch_transaction_pda.0
+                    )
+                })?;
+
+        let vault_pda = get_vault_pda(&multisig, batch.vault_index, Some(&program_id));
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to execute a batch transaction, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", transaction_creator);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Batch:       {}", batch_pda.0);
+        println!("Batch Index:       {}", batch_index);
+        println!("Vault:       {}", vault_pda.0);
+        println!("Step:       {} of {}", step_index, batch.size);
+        println!("Batch Transaction:       {}", batch_transaction_pda.0);
+        println!();
+        load_message_idls(&rpc_client, &batch_transaction.message).await;
+        print_transaction_message(&batch_transaction.message);
+        print_token_amounts(&rpc_client, &batch_transaction.message).await?;
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        // Ephemeral signers of batch transactions are derived from the batch, not the transaction.
+        let remaining_account_metas = message_to_execute_account_metas(
+            &rpc_client,
+            batch_transaction.message,
+            batch_transaction.ephemeral_signer_bumps,
+            &vault_pda.0,
+            &batch_pda.0,
+            Some(&program_id),
+        )
+        .await;
+
+        let mut batch_execute_account_metas = BatchExecuteTransactionAccounts {
+            multisig,
+            member: transaction_creator,
+            proposal: proposal_pda.0,
+            batch: batch_pda.0,
+            transaction: batch_transaction_pda.0,
+        }
+        .to_account_metas(Some(false));
+        batch_execute_account_metas.extend(remaining_account_metas.0);
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &transaction_creator,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(
+                    compute_unit_limit.unwrap_or(200_000),
+                ),
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: batch_execute_account_metas,
+                    data: BatchExecuteTransactionData {}.data(),
+                    program_id,
+                },
+            ],
+            remaining_account_metas.1.as_slice(),
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*transaction_creator_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Executed step {} of {} of Batch {}. Signature: {}",
+            step_index,
+            batch.size,
+            batch_pda.0,
+            signature.green()
+        );
+
+        Ok(())
+    }
+}
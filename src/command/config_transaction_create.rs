@@ -0,0 +1,380 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::client::get_multisig;
+use squads_multisig::pda::{get_proposal_pda, get_spending_limit_pda, get_transaction_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::ConfigTransactionCreateArgs;
+use squads_multisig::squads_multisig_program::ProposalCreateArgs;
+use squads_multisig::squads_multisig_program::accounts::ConfigTransactionCreate as ConfigTransactionCreateAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProposalCreate as ProposalCreateAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::ConfigTransactionCreate as ConfigTransactionCreateData;
+use squads_multisig::squads_multisig_program::instruction::ProposalCreate as ProposalCreateData;
+use squads_multisig::state::ConfigAction;
+
+use crate::fingerprint::{config_transaction_fingerprint, format_fingerprint};
+use crate::token_amount::{fetch_mint_info, parse_ui_amount};
+use crate::utils::{
+    create_signer_from_path, describe_config_action, load_config_action_mints, parse_members,
+    parse_period, send_and_confirm_transaction, transaction_details,
+};
+
+#[derive(Args)]
+pub struct ConfigTransactionCreate {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of the member creating the config transaction
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig to change
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Members to add, in the format <public_key>,<permission>
+    #[arg(long, value_delimiter = ' ')]
+    add_member: Vec<String>,
+
+    /// Members to remove
+    #[arg(long, value_delimiter = ' ')]
+    remove_member: Vec<String>,
+
+    /// New approval threshold
+    #[arg(long)]
+    change_threshold: Option<u16>,
+
+    /// New time lock in seconds
+    #[arg(long)]
+    set_time_lock: Option<u32>,
+
+    /// New rent collector, or "none" to disable rent collection
+    #[arg(long)]
+    set_rent_collector: Option<String>,
+
+    /// Mint of the spending limit to add
+    #[arg(long)]
+    spending_limit_mint: Option<String>,
+
+    /// Vault index the spending limit applies to
+    #[arg(long, default_value_t = 0)]
+    spending_limit_vault_index: u8,
+
+    /// Amount of the spending limit in base units of the mint
+    #[arg(long, conflicts_with = "spending_limit_amount")]
+    spending_limit_token_amount_u64: Option<u64>,
+
+    /// Amount of the spending limit in tokens, e.g. 12.5, converted with the decimals of the mint
+    #[arg(long)]
+    spending_limit_amount: Option<String>,
+
+    /// Reset period of the spending limit: OneTime, Day, Week or Month
+    #[arg(long, default_value = "OneTime")]
+    spending_limit_period: String,
+
+    /// Members allowed to use the spending limit
+    #[arg(long, value_delimiter = ' ')]
+    spending_limit_members: Vec<String>,
+
+    /// Allowed destinations of the spending limit, any destination if empty
+    #[arg(long, value_delimiter = ' ')]
+    spending_limit_destinations: Vec<String>,
+
+    /// Spending limits to remove
+    #[arg(long, value_delimiter = ' ')]
+    remove_spending_limit: Vec<String>,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    /// Create the proposal as a Draft, voting only opens once it is activated
+    #[arg(long, default_value_t = false)]
+    draft: bool,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl ConfigTransactionCreate {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            add_member,
+            remove_member,
+            change_threshold,
+            set_time_lock,
+            set_rent_collector,
+            spending_limit_mint,
+            spending_limit_vault_index,
+            spending_limit_token_amount_u64,
+            spending_limit_amount,
+            spending_limit_period,
+            spending_limit_members,
+            spending_limit_destinations,
+            remove_spending_limit,
+            memo,
+            draft,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
+
+        let transaction_creator = transaction_creator_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = &RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let mut actions = Vec::new();
+
+        let new_members = parse_members(add_member).unwrap_or_else(|err| {
+            eprintln!("Error parsing members: {}", err);
+            std::process::exit(1);
+        });
+        for new_member in new_members {
+            actions.push(ConfigAction::AddMember { new_member });
+        }
+
+        for old_member in remove_member {
+            actions.push(ConfigAction::RemoveMember {
+                old_member: Pubkey::from_str(&old_member).expect("Invalid member address"),
+            });
+        }
+
+        if let Some(new_threshold) = change_threshold {
+            actions.push(ConfigAction::ChangeThreshold { new_threshold });
+        }
+
+        if let Some(new_time_lock) = set_time_lock {
+            actions.push(ConfigAction::SetTimeLock { new_time_lock });
+        }
+
+        if let Some(new_rent_collector) = set_rent_collector {
+            let new_rent_collector = match new_rent_collector.to_lowercase().as_str() {
+                "none" => None,
+                _ => Some(
+                    Pubkey::from_str(&new_rent_collector).expect("Invalid rent collector address"),
+                ),
+            };
+            actions.push(ConfigAction::SetRentCollector { new_rent_collector });
+        }
+
+        // A fresh create key keeps the spending limit PDA unique, the same way multisigs are created.
+        let spending_limit_create_key = Keypair::new().pubkey();
+        if let Some(mint) = spending_limit_mint {
+            let mint = Pubkey::from_str(&mint).expect("Invalid spending limit mint");
+            let amount = match (spending_limit_token_amount_u64, spending_limit_amount) {
+                (Some(token_amount_u64), _) => token_amount_u64,
+                (None, Some(amount)) => {
+                    let mint_info = fetch_mint_info(rpc_client, &mint).await?;
+                    parse_ui_amount(&amount, mint_info.decimals)?
+                }
+                (None, None) => {
+                    return Err(eyre!(
+                        "--spending-limit-amount or --spending-limit-token-amount-u64 is required with --spending-limit-mint"
+                    ));
+                }
+            };
+            if spending_limit_members.is_empty() {
+                return Err(eyre!(
+                    "--spending-limit-members is required with --spending-limit-mint"
+                ));
+            }
+            let mut members = spending_limit_members
+                .iter()
+                .map(|m| Pubkey::from_str(m).expect("Invalid spending limit member"))
+                .collect::<Vec<_>>();
+            // The program rejects unsorted or duplicated spending limit members.
+            members.sort();
+            members.dedup();
+            actions.push(ConfigAction::AddSpendingLimit {
+                create_key: spending_limit_create_key,
+                vault_index: spending_limit_vault_index,
+                mint,
+                amount,
+                period: parse_period(&spending_limit_period).map_err(|e| eyre!(e))?,
+                members,
+                destinations: spending_limit_destinations
+                    .iter()
+                    .map(|d| Pubkey::from_str(d).expect("Invalid spending limit destination"))
+                    .collect(),
+            });
+        }
+
+        for spending_limit in remove_spending_limit {
+            actions.push(ConfigAction::RemoveSpendingLimit {
+                spending_limit: Pubkey::from_str(&spending_limit)
+                    .expect("Invalid spending limit address"),
+            });
+        }
+
+        if actions.is_empty() {
+            return Err(eyre!(
+                "No config actions given. Use e.g. --add-member, --remove-member or --change-threshold"
+            ));
+        }
+
+        let multisig_data = get_multisig(rpc_client, &multisig).await?;
+        load_config_action_mints(rpc_client, &actions).await;
+
+        let transaction_index = multisig_data.transaction_index + 1;
+
+        let transaction_pda = get_transaction_pda(&multisig, transaction_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig, transaction_index, Some(&program_id));
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to create a config transaction, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", transaction_creator);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Transaction Index:       {}", transaction_index);
+        if draft {
+            println!(
+                "Proposal Status:       {}",
+                "Draft, open voting with `proposal-vote --action activate`".yellow()
+            );
+        }
+        println!(
+            "Current Threshold:       {} of {} members",
+            multisig_data.threshold,
+            multisig_data.members.len()
+        );
+        println!();
+        println!("🛠️ Config Actions");
+        for (i, action) in actions.iter().enumerate() {
+            println!(
+                "  {}. {}",
+                i + 1,
+                describe_config_action(action).bright_cyan()
+            );
+            if let ConfigAction::AddSpendingLimit { create_key, .. } = action {
+                println!(
+                    "     Spending Limit PDA: {}",
+                    get_spending_limit_pda(&multisig, create_key, Some(&program_id)).0
+                );
+            }
+        }
+        let fingerprint = config_transaction_fingerprint(&multisig, transaction_index, &actions);
+        println!(
+            "Fingerprint:       {}",
+            format_fingerprint(&fingerprint).bright_green().bold()
+        );
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &transaction_creator,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: ConfigTransactionCreateAccounts {
+                        creator: transaction_creator,
+                        rent_payer: transaction_creator,
+                        transaction: transaction_pda.0,
+                        multisig,
+                        system_program: solana_sdk::system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: ConfigTransactionCreateData {
+                        args: ConfigTransactionCreateArgs { actions, memo },
+                    }
+                    .data(),
+                    program_id,
+                },
+                Instruction {
+                    accounts: ProposalCreateAccounts {
+                        creator: transaction_creator,
+                        rent_payer: transaction_creator,
+                        proposal: proposal_pda.0,
+                        multisig,
+                        system_program: solana_sdk::system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: ProposalCreateData {
+                        args: ProposalCreateArgs {
+                            draft,
+                            transaction_index,
+                        },
+                    }
+                    .data(),
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*transaction_creator_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, rpc_client).await?;
+        println!(
+            "✅ Config transaction created successfully. Signature: {}",
+            signature.green()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,347 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::client::{
+    ConfigTransactionExecuteAccounts, config_transaction_execute, get_multisig,
+};
+use squads_multisig::pda::{get_proposal_pda, get_spending_limit_pda, get_transaction_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::state::{ConfigAction, ConfigTransaction, Multisig, Proposal, ProposalStatus};
+
+use crate::utils::{
+    create_signer_from_path, describe_config_action, format_permissions, load_config_action_mints,
+    send_and_confirm_transaction, transaction_details,
+};
+
+#[derive(Args)]
+pub struct ConfigTransactionExecute {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of a member with Execute permission
+    #[arg(long)]
+    keypair: String,
+
+    /// Index of the config transaction to execute
+    #[arg(long)]
+    transaction_index: u64,
+
+    /// The multisig where the transaction has been proposed
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+
+    #[arg(long)]
+    compute_unit_limit: Option<u32>,
+}
+
+impl ConfigTransactionExecute {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            transaction_index,
+            priority_fee_lamports,
+            compute_unit_limit,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
+
+        let transaction_creator = transaction_creator_keypair.pubkey();
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let proposal_pda = get_proposal_pda(&multisig, transaction_index, Some(&program_id));
+
+        let transaction_pda = get_transaction_pda(&multisig, transaction_index, Some(&program_id));
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig_account_len = rpc_client
+            .get_account(&multisig)
+            .await
+            .expect("Failed to get multisig account")
+            .data
+            .len();
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+
+        let transaction_account_data = rpc_client
+            .get_account(&transaction_pda.0)
+            .await
+            .expect("Failed to get transaction account")
+            .data;
+        let config_transaction = ConfigTransaction::try_deserialize(
+            &mut transaction_account_data.as_slice(),
+        )
+        .map_err(|_| {
+            eyre!(
+                "Transaction {} is not a config transaction",
+                transaction_index
+            )
+        })?;
+
+        let proposal_account_data = rpc_client
+            .get_account(&proposal_pda.0)
+            .await
+            .expect("Failed to get proposal account")
+            .data;
+        let proposal = Proposal::try_deserialize(&mut proposal_account_data.as_slice())?;
+
+        let resulting_multisig = apply_config_actions(&multisig_data, &config_transaction.actions)?;
+
+        // SpendingLimit accounts are passed as remaining accounts, created or closed by the program.
+        let spending_limit_accounts: Vec<Pubkey> = config_transaction
+            .actions
+            .iter()
+            .filter_map(|action| match action {
+                ConfigAction::AddSpendingLimit { create_key, .. } => {
+                    Some(get_spending_limit_pda(&multisig, create_key, Some(&program_id)).0)
+                }
+                ConfigAction::RemoveSpendingLimit { spending_limit } => Some(*spending_limit),
+                _ => None,
+            })
+            .collect();
+
+        // A rent payer is needed for spending limit accounts and when the multisig has to grow.
+        let needs_rent_payer = !spending_limit_accounts.is_empty()
+            || multisig_account_len < Multisig::size(resulting_multisig.members.len());
+
+        load_config_action_mints(&rpc_client, &config_transaction.actions).await;
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to execute a config transaction, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", transaction_creator);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Transaction Index:       {}", transaction_index);
+        println!(
+            "Transaction is proposed by: {}",
+            config_transaction.creator.to_string().bright_green()
+        );
+        println!("Proposal Status:       {:?}", proposal.status);
+        println!();
+        println!("🛠️ Config Actions");
+        for (i, action) in config_transaction.actions.iter().enumerate() {
+            println!(
+                "  {}. {}",
+                i + 1,
+                describe_config_action(action).bright_cyan()
+            );
+        }
+        println!();
+        println!("📋 Multisig State");
+        print_multisig_change(&multisig_data, &resulting_multisig);
+        if !spending_limit_accounts.is_empty() {
+            println!("Spending Limit Accounts:");
+            for spending_limit in &spending_limit_accounts {
+                println!("  - {}", spending_limit);
+            }
+        }
+        println!(
+            "Rent Payer:       {}",
+            if needs_rent_payer {
+                transaction_creator.to_string()
+            } else {
+                "Not required".to_string()
+            }
+        );
+        println!();
+
+        if !matches!(proposal.status, ProposalStatus::Approved { .. }) {
+            println!(
+                "{}",
+                "⚠️ WARNING: The proposal is not Approved, the program will reject the execution."
+                    .red()
+            );
+        }
+        if transaction_index <= multisig_data.stale_transaction_index {
+            println!(
+                "{}",
+                "⚠️ WARNING: The config transaction is stale and can never be executed.".red()
+            );
+        }
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &transaction_creator,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_limit(
+                    compute_unit_limit.unwrap_or(200_000),
+                ),
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                config_transaction_execute(
+                    ConfigTransactionExecuteAccounts {
+                        multisig,
+                        member: transaction_creator,
+                        proposal: proposal_pda.0,
+                        transaction: transaction_pda.0,
+                        rent_payer: needs_rent_payer.then_some(transaction_creator),
+                        system_program: needs_rent_payer.then(system_program::id),
+                    },
+                    spending_limit_accounts,
+                    Some(program_id),
+                ),
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*transaction_creator_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Executed Config Transaction. Signature: {}",
+            signature.green()
+        );
+
+        Ok(())
+    }
+}
+
+/// Replays the config actions on a copy of the multisig, mirroring `config_transaction_execute`.
+pub fn apply_config_actions(
+    multisig: &Multisig,
+    actions: &[ConfigAction],
+) -> eyre::Result<Multisig> {
+    let mut multisig = multisig.clone();
+    for action in actions {
+        match action {
+            ConfigAction::AddMember { new_member } => multisig.add_member(new_member.clone()),
+            ConfigAction::RemoveMember { old_member } => multisig
+                .remove_member(*old_member)
+                .map_err(|_| eyre!("{} is not a member of the multisig", old_member))?,
+            ConfigAction::ChangeThreshold { new_threshold } => multisig.threshold = *new_threshold,
+            ConfigAction::SetTimeLock { new_time_lock } => multisig.time_lock = *new_time_lock,
+            ConfigAction::SetRentCollector { new_rent_collector } => {
+                multisig.rent_collector = *new_rent_collector
+            }
+            _ => {}
+        }
+    }
+    Ok(multisig)
+}
+
+pub fn print_multisig_change(before: &Multisig, after: &Multisig) {
+    println!(
+        "Threshold:          {} -> {}",
+        before.threshold,
+        after.threshold.to_string().bright_cyan()
+    );
+    println!(
+        "Time Lock:          {}s -> {}s",
+        before.time_lock,
+        after.time_lock.to_string().bright_cyan()
+    );
+    println!(
+        "Rent Collector:     {} -> {}",
+        before
+            .rent_collector
+            .map(|k| k.to_string())
+            .unwrap_or_else(|| "None".to_string()),
+        after
+            .rent_collector
+            .map(|k| k.to_string())
+            .unwrap_or_else(|| "None".to_string())
+            .bright_cyan()
+    );
+    println!("Members:");
+    for member in &after.members {
+        let marker = match before.members.iter().find(|m| m.key == member.key) {
+            None => "+".green(),
+            Some(old) if old.permissions != member.permissions => "~".yellow(),
+            Some(_) => " ".normal(),
+        };
+        println!(
+            "  {} {}: {}",
+            marker,
+            member.key,
+            format_permissions(&member.permissions)
+        );
+    }
+    for member in &before.members {
+        if !after.members.iter().any(|m| m.key == member.key) {
+            println!(
+                "  {} {}: {}",
+                "-".red(),
+                member.key.to_string().red(),
+                format_permissions(&member.permissions)
+            );
+        }
+    }
+
+    let voters = Multisig::num_voters(&after.members);
+    if usize::from(after.threshold) > voters {
+        println!(
+            "{}",
+            format!(
+                "⚠️ WARNING: Threshold {} is higher than the {} members with Vote permission, the program will reject this.",
+                after.threshold, voters
+            )
+            .red()
+        );
+    }
+}
@@ -0,0 +1,191 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::state::VaultBatchTransaction;
+use squads_multisig::state::{Batch, Proposal};
+use std::str::FromStr;
+
+use crate::command::display_transaction::{
+    load_message_idls, print_token_amounts, print_transaction_message, transaction_message_json,
+};
+use crate::output;
+use crate::utils::get_batch_transaction_pda;
+
+#[derive(Args)]
+pub struct DisplayBatch {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The multisig owning the batch
+    #[arg(long)]
+    multisig_address: String,
+
+    /// Transaction index of the batch
+    #[arg(long)]
+    batch_index: u64,
+}
+
+impl DisplayBatch {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            multisig_address,
+            batch_index,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let multisig_address =
+            Pubkey::from_str(&multisig_address).expect("Invalid multisig address");
+
+        let batch_pda = get_transaction_pda(&multisig_address, batch_index, Some(&program_id));
+        let proposal_pda = get_proposal_pda(&multisig_address, batch_index, Some(&program_id));
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let batch_account = rpc_client
+            .get_account(&batch_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get batch account: {}", e))?;
+        let batch = Batch::try_deserialize(&mut batch_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a batch account", batch_pda.0))?;
+
+        println!("Batch -> {}", batch_pda.0);
+        print_batch(&batch, &multisig_address, &program_id);
+        let proposal_status = match rpc_client.get_account(&proposal_pda.0).await {
+            Ok(account) => match Proposal::try_deserialize(&mut account.data.as_slice()) {
+                Ok(proposal) => format!("{:?}", proposal.status),
+                Err(_) => "undecodable".to_string(),
+            },
+            Err(_) => "not found".to_string(),
+        };
+        println!("Proposal Status: {}", proposal_status);
+        output::set(
+            "batch",
+            json!({
+                "address": batch_pda.0.to_string(),
+                "creator": batch.creator.to_string(),
+                "vault_index": batch.vault_index,
+                "size": batch.size,
+                "executed_transaction_index": batch.executed_transaction_index,
+                "proposal_status": proposal_status,
+            }),
+        );
+
+        for step_index in 1..=batch.size {
+            println!();
+            print_batch_transaction(
+                &rpc_client,
+                &multisig_address,
+                batch_index,
+                &batch,
+                step_index,
+                &program_id,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn print_batch(batch: &Batch, multisig_address: &Pubkey, program_id: &Pubkey) {
+    println!(
+        "Batch is created by: {}",
+        batch.creator.to_string().bright_green()
+    );
+    println!(
+        "Vault:  {} (index {})",
+        get_vault_pda(multisig_address, batch.vault_index, Some(program_id)).0,
+        batch.vault_index
+    );
+    println!(
+        "Executed: {} of {} transactions",
+        batch.executed_transaction_index, batch.size
+    );
+}
+
+/// Prints the `step_index`-th (1-based) transaction of a batch with its execution state.
+pub async fn print_batch_transaction(
+    rpc_client: &RpcClient,
+    multisig_address: &Pubkey,
+    batch_index: u64,
+    batch: &Batch,
+    step_index: u32,
+    program_id: &Pubkey,
+) -> eyre::Result<()> {
+    if step_index == 0 || step_index > batch.size {
+        return Err(eyre!(
+            "Batch transaction {} does not exist, the batch has {} transactions",
+            step_index,
+            batch.size
+        ));
+    }
+    let batch_transaction_pda =
+        get_batch_transaction_pda(multisig_address, batch_index, step_index, Some(program_id));
+    let executed = if step_index <= batch.executed_transaction_index {
+        "executed".bright_black()
+    } else if step_index == batch.executed_transaction_index + 1 {
+        "next".bright_green()
+    } else {
+        "pending".yellow()
+    };
+    println!(
+        "📦 Transaction {} of {} -> {} ({})",
+        step_index, batch.size, batch_transaction_pda.0, executed
+    );
+
+    let batch_transaction = match rpc_client.get_account(&batch_transaction_pda.0).await {
+        Ok(account) => VaultBatchTransaction::try_deserialize(&mut account.data.as_slice())
+            .map_err(|_| {
+                eyre!(
+                    "{} is not a batch transaction account",
+                    batch_transaction_pda.0
+                )
+            })?,
+        Err(_) => {
+            // Executed transactions may already have been closed.
+            println!("  {}", "Account not found".red());
+            return Ok(());
+        }
+    };
+    let ephemeral_signer_count = batch_transaction.ephemeral_signer_bumps.len();
+    if ephemeral_signer_count > 0 {
+        println!(
+            "  Additional Signers: {} ephemeral accounts (used for signing program instructions)",
+            ephemeral_signer_count
+        );
+    } else {
+        println!("  Additional Signers: None");
+    }
+    load_message_idls(rpc_client, &batch_transaction.message).await;
+    print_transaction_message(&batch_transaction.message);
+    print_token_amounts(rpc_client, &batch_transaction.message).await?;
+    output::push(
+        "batch_transactions",
+        json!({
+            "address": batch_transaction_pda.0.to_string(),
+            "index": step_index,
+            "executed": step_index <= batch.executed_transaction_index,
+            "ephemeral_signer_count": ephemeral_signer_count,
+            "message": transaction_message_json(&batch_transaction.message),
+        }),
+    );
+    Ok(())
+}
@@ -0,0 +1,188 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::{Value, json};
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::state::{Multisig, Permission};
+use std::str::FromStr;
+
+use crate::output;
+use crate::utils::format_permissions;
+
+#[derive(Args)]
+pub struct DisplayMultisig {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The multisig account to inspect
+    #[arg(long)]
+    multisig_address: String,
+}
+
+impl DisplayMultisig {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            multisig_address,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let multisig_address =
+            Pubkey::from_str(&multisig_address).expect("Invalid multisig address");
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let multisig = fetch_multisig(&rpc_client, &multisig_address, &program_id).await?;
+
+        println!("Multisig -> {}", multisig_address);
+        print_multisig(&multisig);
+
+        let warnings = multisig_warnings(&multisig);
+        output::set("multisig", multisig_json(&multisig_address, &multisig));
+        output::set("warnings", json!(warnings));
+        if !warnings.is_empty() {
+            println!();
+            for warning in warnings {
+                println!("{}", format!("⚠️ WARNING: {}", warning).red());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub async fn fetch_multisig(
+    rpc_client: &RpcClient,
+    multisig: &Pubkey,
+    program_id: &Pubkey,
+) -> eyre::Result<Multisig> {
+    let account = rpc_client
+        .get_account(multisig)
+        .await
+        .map_err(|e| eyre!("Failed to get multisig account: {}", e))?;
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+            multisig,
+            account.owner
+        ));
+    }
+    Multisig::try_deserialize(&mut account.data.as_slice())
+        .map_err(|_| eyre!("{} is not a multisig account", multisig))
+}
+
+pub fn print_multisig(multisig: &Multisig) {
+    println!("Create Key:               {}", multisig.create_key);
+    println!(
+        "Threshold:                {} of {} voters ({} members)",
+        multisig.threshold,
+        Multisig::num_voters(&multisig.members),
+        multisig.members.len()
+    );
+    println!("Time Lock:                {}s", multisig.time_lock);
+    println!("Transaction Index:        {}", multisig.transaction_index);
+    println!(
+        "Stale Transaction Index:  {}",
+        multisig.stale_transaction_index
+    );
+    println!(
+        "Config Authority:         {}",
+        if multisig.config_authority == Pubkey::default() {
+            "None (autonomous)".to_string()
+        } else {
+            multisig.config_authority.to_string()
+        }
+    );
+    println!(
+        "Rent Collector:           {}",
+        multisig
+            .rent_collector
+            .map(|k| k.to_string())
+            .unwrap_or_else(|| "None".to_string())
+    );
+    println!("Bump:                     {}", multisig.bump);
+    println!("Members:");
+    for member in &multisig.members {
+        println!(
+            "  - {}: {}",
+            member.key,
+            format_permissions(&member.permissions)
+        );
+    }
+}
+
+pub fn multisig_json(address: &Pubkey, multisig: &Multisig) -> Value {
+    json!({
+        "address": address.to_string(),
+        "create_key": multisig.create_key.to_string(),
+        "config_authority": (multisig.config_authority != Pubkey::default())
+            .then(|| multisig.config_authority.to_string()),
+        "threshold": multisig.threshold,
+        "time_lock": multisig.time_lock,
+        "transaction_index": multisig.transaction_index,
+        "stale_transaction_index": multisig.stale_transaction_index,
+        "rent_collector": multisig.rent_collector.map(|k| k.to_string()),
+        "bump": multisig.bump,
+        "members": multisig
+            .members
+            .iter()
+            .map(|member| json!({
+                "key": member.key.to_string(),
+                "permissions": member.permissions.mask,
+                "permission_names": format_permissions(&member.permissions),
+            }))
+            .collect::<Vec<_>>(),
+    })
+}
+
+/// Configurations that can lock the multisig or hand it to fewer keys than it appears to need.
+pub fn multisig_warnings(multisig: &Multisig) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let voters = Multisig::num_voters(&multisig.members);
+    let has = |permission| {
+        multisig
+            .members
+            .iter()
+            .any(|m| m.permissions.has(permission))
+    };
+
+    if usize::from(multisig.threshold) > voters {
+        warnings.push(format!(
+            "Threshold {} is higher than the {} members with Vote permission, no proposal can pass.",
+            multisig.threshold, voters
+        ));
+    }
+    if multisig.threshold == 1 && voters > 1 {
+        warnings.push(format!(
+            "Threshold is 1, any single one of the {} voters can approve a transaction alone.",
+            voters
+        ));
+    }
+    if !has(Permission::Initiate) {
+        warnings.push("No member has Initiate permission, no transaction can be proposed.".into());
+    }
+    if !has(Permission::Execute) {
+        warnings.push("No member has Execute permission, no transaction can be executed.".into());
+    }
+    if multisig.config_authority != Pubkey::default() {
+        warnings.push(format!(
+            "The multisig is controlled, {} can change members and threshold without a vote.",
+            multisig.config_authority
+        ));
+    }
+    warnings
+}
@@ -0,0 +1,186 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::json;
+use solana_sdk::account::from_account;
+use solana_sdk::clock::Clock;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::sysvar;
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::state::SpendingLimit;
+use std::str::FromStr;
+
+use crate::output;
+use crate::token_amount::{MintInfo, fetch_mint_info, format_ui_amount};
+
+#[derive(Args)]
+pub struct DisplaySpendingLimit {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The spending limit account to inspect
+    #[arg(long)]
+    spending_limit: String,
+}
+
+impl DisplaySpendingLimit {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            spending_limit,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let spending_limit_key =
+            Pubkey::from_str(&spending_limit).expect("Invalid spending limit address");
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let spending_limit =
+            fetch_spending_limit(&rpc_client, &spending_limit_key, &program_id).await?;
+        let now = fetch_unix_timestamp(&rpc_client).await?;
+        let mint_info = fetch_mint_info(&rpc_client, &spending_limit.mint).await?;
+
+        println!("Spending Limit -> {}", spending_limit_key);
+        print_spending_limit(&spending_limit, &mint_info, now);
+
+        let (remaining_amount, next_reset) = spending_limit_window(&spending_limit, now);
+        output::set(
+            "spending_limit",
+            json!({
+                "address": spending_limit_key.to_string(),
+                "multisig": spending_limit.multisig.to_string(),
+                "create_key": spending_limit.create_key.to_string(),
+                "vault_index": spending_limit.vault_index,
+                "mint": spending_limit.mint.to_string(),
+                "symbol": mint_info.symbol,
+                "decimals": mint_info.decimals,
+                "amount": spending_limit.amount.to_string(),
+                "ui_amount": format_ui_amount(spending_limit.amount, mint_info.decimals),
+                "period": format!("{:?}", spending_limit.period),
+                "remaining_amount": remaining_amount.to_string(),
+                "remaining_ui_amount": format_ui_amount(remaining_amount, mint_info.decimals),
+                "next_reset": next_reset,
+                "members": spending_limit.members.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+                "destinations": spending_limit
+                    .destinations
+                    .iter()
+                    .map(|k| k.to_string())
+                    .collect::<Vec<_>>(),
+            }),
+        );
+
+        Ok(())
+    }
+}
+
+pub async fn fetch_spending_limit(
+    rpc_client: &RpcClient,
+    spending_limit: &Pubkey,
+    program_id: &Pubkey,
+) -> eyre::Result<SpendingLimit> {
+    let account = rpc_client
+        .get_account(spending_limit)
+        .await
+        .map_err(|e| eyre!("Failed to get spending limit account: {}", e))?;
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+            spending_limit,
+            account.owner
+        ));
+    }
+    SpendingLimit::try_deserialize(&mut account.data.as_slice())
+        .map_err(|_| eyre!("{} is not a spending limit account", spending_limit))
+}
+
+/// Reads the cluster time, the spending limit period is measured against it and not the local clock.
+pub async fn fetch_unix_timestamp(rpc_client: &RpcClient) -> eyre::Result<i64> {
+    let clock_account = rpc_client.get_account(&sysvar::clock::id()).await?;
+    let clock: Clock =
+        from_account(&clock_account).ok_or_else(|| eyre!("Failed to decode the clock sysvar"))?;
+    Ok(clock.unix_timestamp)
+}
+
+/// Returns the amount usable right now and the start of the next period, replaying the
+/// reset logic of `spending_limit_use`.
+pub fn spending_limit_window(spending_limit: &SpendingLimit, now: i64) -> (u64, Option<i64>) {
+    match spending_limit.period.to_seconds() {
+        None => (spending_limit.remaining_amount, None),
+        Some(reset_period) => {
+            let passed_since_last_reset = now.saturating_sub(spending_limit.last_reset);
+            if passed_since_last_reset > reset_period {
+                let periods_passed = passed_since_last_reset / reset_period;
+                let last_reset = spending_limit.last_reset + periods_passed * reset_period;
+                (spending_limit.amount, Some(last_reset + reset_period))
+            } else {
+                (
+                    spending_limit.remaining_amount,
+                    Some(spending_limit.last_reset + reset_period),
+                )
+            }
+        }
+    }
+}
+
+pub fn print_spending_limit(spending_limit: &SpendingLimit, mint_info: &MintInfo, now: i64) {
+    let (remaining_amount, next_reset) = spending_limit_window(spending_limit, now);
+
+    println!("Multisig:           {}", spending_limit.multisig);
+    println!("Create Key:         {}", spending_limit.create_key);
+    println!("Vault Index:        {}", spending_limit.vault_index);
+    println!("Mint:               {}", describe_mint(mint_info));
+    println!(
+        "Amount:             {}",
+        mint_info.display(spending_limit.amount)
+    );
+    println!("Period:             {:?}", spending_limit.period);
+    println!(
+        "Remaining Amount:   {}",
+        mint_info.display(remaining_amount).bright_green()
+    );
+    match next_reset {
+        Some(next_reset) => println!(
+            "Next Reset:         in {}s (unix {})",
+            next_reset.saturating_sub(now),
+            next_reset
+        ),
+        None => println!("Next Reset:         never (one time limit)"),
+    }
+    println!("Members:");
+    for member in &spending_limit.members {
+        println!("  - {}", member);
+    }
+    if spending_limit.destinations.is_empty() {
+        println!("Destinations:       {}", "any".yellow());
+    } else {
+        println!("Destinations:");
+        for destination in &spending_limit.destinations {
+            println!("  - {}", destination);
+        }
+    }
+}
+
+/// `SOL`, or the mint followed by its symbol when the mint has metadata.
+pub fn describe_mint(mint_info: &MintInfo) -> String {
+    if mint_info.mint == Pubkey::default() {
+        return "SOL".to_string();
+    }
+    match &mint_info.symbol {
+        Some(symbol) => format!("{} ({})", mint_info.mint, symbol),
+        None => mint_info.mint.to_string(),
+    }
+}
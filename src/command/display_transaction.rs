@@ -1,9 +1,12 @@
 use clap::Args;
 use colored::Colorize;
 use eyre::eyre;
-use serde_json::Value;
 use serde_json::to_string_pretty;
+use serde_json::{Value, json};
 use solana_program::address_lookup_table::state::AddressLookupTable;
+use solana_program::program_pack::Pack;
+use solana_program::program_utils::limited_deserialize;
+use solana_program::system_instruction::SystemInstruction;
 use solana_sdk::instruction::CompiledInstruction;
 use solana_sdk::loader_instruction;
 use solana_sdk::message::v0::LoadedAddresses;
@@ -12,8 +15,10 @@ use solana_sdk::message::{AccountKeys, VersionedMessage};
 use solana_sdk::pubkey::Pubkey;
 use solana_transaction_status::parse_accounts::parse_v0_message_accounts;
 use solana_transaction_status::parse_instruction::parse;
+use spl_token::instruction::TokenInstruction;
 use squads_multisig::anchor_lang::AnchorDeserialize;
-use squads_multisig::anchor_lang::{AccountDeserialize, InstructionData};
+use squads_multisig::anchor_lang::{AccountDeserialize, Discriminator, InstructionData};
+use squads_multisig::client::get_multisig;
 use squads_multisig::pda::{
     get_ephemeral_signer_pda, get_multisig_pda, get_proposal_pda, get_transaction_pda,
     get_vault_pda,
@@ -29,8 +34,26 @@ use std::io::Cursor;
 //use squads_multisig::pda::get_transaction_pda;
 use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::state::VaultTransaction;
+use squads_multisig::state::{Batch, ConfigAction, ConfigTransaction, Multisig};
 use std::str::FromStr;
 
+use crate::address_book;
+use crate::command::config_transaction_execute::{apply_config_actions, print_multisig_change};
+use crate::command::display_batch::{print_batch, print_batch_transaction};
+use crate::fingerprint::{
+    config_transaction_fingerprint, fingerprint_json, format_fingerprint,
+    vault_transaction_fingerprint,
+};
+use crate::output;
+use crate::squads_decoder::{
+    DecodeError, InstructionMapResult, PdaStatus, idl_registry, load_onchain_idls, map_instruction,
+};
+use crate::token_amount::{MintInfo, TOKEN_2022_PROGRAM_ID, fetch_mint_info, format_ui_amount};
+use crate::utils::{
+    MyInstruction, describe_config_action, load_config_action_mints, print_decode_warnings,
+    print_idl_source, print_pda_checks,
+};
+
 #[derive(Args)]
 pub struct DisplayTransaction {
     /// RPC URL
@@ -48,6 +71,10 @@ pub struct DisplayTransaction {
     // index to derive the tx
     #[arg(long)]
     transaction_index: u64,
+
+    /// For batches, the 1-based index of the batch transaction to display
+    #[arg(long)]
+    batch_transaction_index: Option<u32>,
 }
 
 impl DisplayTransaction {
@@ -58,6 +85,7 @@ impl DisplayTransaction {
             program_id,
             multisig_address,
             transaction_index,
+            batch_transaction_index,
         } = self;
 
         let program_id =
@@ -74,20 +102,125 @@ impl DisplayTransaction {
         // Initialize RPC client
         let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
         let rpc_client = RpcClient::new(rpc_url.to_string());
-        let transaction_account_data = rpc_client
+        let transaction_account = rpc_client
             .get_account(&transaction_pda.0)
             .await
-            .expect("Failed to get transaction account")
-            .data;
+            .map_err(|e| eyre!("Failed to get transaction account: {}", e))?;
+        if transaction_account.owner != program_id {
+            return Err(eyre!(
+                "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+                transaction_pda.0,
+                transaction_account.owner
+            ));
+        }
+        let transaction_account_data = transaction_account.data;
+
+        // The transaction PDA holds a vault transaction, a config transaction or a batch.
+        let discriminator = transaction_account_data
+            .get(..8)
+            .ok_or_else(|| eyre!("{} is too short to be a transaction", transaction_pda.0))?;
+        if discriminator == ConfigTransaction::DISCRIMINATOR {
+            let config_transaction =
+                ConfigTransaction::try_deserialize(&mut transaction_account_data.as_slice())
+                    .map_err(|_| eyre!("{} is not a config transaction", transaction_pda.0))?;
+            let multisig = get_multisig(&rpc_client, &multisig_address).await?;
+            load_config_action_mints(&rpc_client, &config_transaction.actions).await;
+            let fingerprint = config_transaction_fingerprint(
+                &multisig_address,
+                config_transaction.index,
+                &config_transaction.actions,
+            );
+            println!("Config Transaction -> {}", transaction_pda.0);
+            println!(
+                "Fingerprint: {}",
+                format_fingerprint(&fingerprint).bright_green().bold()
+            );
+            print_config_transaction(&config_transaction, &multisig);
+            output::set(
+                "transaction",
+                json!({
+                    "address": transaction_pda.0.to_string(),
+                    "account_type": "config_transaction",
+                    "multisig": multisig_address.to_string(),
+                    "index": config_transaction.index,
+                    "creator": config_transaction.creator.to_string(),
+                    "stale": config_transaction.index <= multisig.stale_transaction_index,
+                    "fingerprint": fingerprint_json(&fingerprint),
+                    "actions": config_transaction
+                        .actions
+                        .iter()
+                        .map(describe_config_action)
+                        .collect::<Vec<_>>(),
+                }),
+            );
+            return Ok(());
+        }
+        if discriminator == Batch::DISCRIMINATOR {
+            let batch = Batch::try_deserialize(&mut transaction_account_data.as_slice())
+                .map_err(|_| eyre!("{} is not a batch", transaction_pda.0))?;
+            println!("Batch -> {}", transaction_pda.0);
+            print_batch(&batch, &multisig_address, &program_id);
+            output::set(
+                "transaction",
+                json!({
+                    "address": transaction_pda.0.to_string(),
+                    "account_type": "batch",
+                    "multisig": multisig_address.to_string(),
+                    "index": transaction_index,
+                    "creator": batch.creator.to_string(),
+                    "vault_index": batch.vault_index,
+                    "size": batch.size,
+                    "executed_transaction_index": batch.executed_transaction_index,
+                }),
+            );
+            match batch_transaction_index {
+                Some(step_index) => {
+                    println!();
+                    print_batch_transaction(
+                        &rpc_client,
+                        &multisig_address,
+                        transaction_index,
+                        &batch,
+                        step_index,
+                        &program_id,
+                    )
+                    .await?;
+                }
+                None if batch.size > 0 => println!(
+                    "Use --batch-transaction-index 1..={} to display a transaction of the batch.",
+                    batch.size
+                ),
+                None => println!("The batch has no transactions yet."),
+            }
+            return Ok(());
+        }
+        if discriminator != VaultTransaction::DISCRIMINATOR {
+            return Err(eyre!(
+                "{} is not a vault transaction, config transaction or batch (discriminator {:?})",
+                transaction_pda.0,
+                discriminator
+            ));
+        }
 
         let mut transaction_account_data_slice = transaction_account_data.as_slice();
 
         let deserialized_account_data =
-            VaultTransaction::try_deserialize(&mut transaction_account_data_slice).unwrap();
+            VaultTransaction::try_deserialize(&mut transaction_account_data_slice)
+                .map_err(|_| eyre!("{} is not a vault transaction", transaction_pda.0))?;
 
         let transaction_message = deserialized_account_data.message;
+        let fingerprint = vault_transaction_fingerprint(
+            &multisig_address,
+            deserialized_account_data.index,
+            deserialized_account_data.vault_index,
+            &transaction_message,
+        );
 
         println!("Transaction -> {:?}", transaction_pda.0);
+        println!(
+            "Fingerprint: {}",
+            format_fingerprint(&fingerprint).bright_green().bold()
+        );
         println!(
             "Transaction is proposed by: {}",
             deserialized_account_data.creator.to_string().bright_green()
@@ -101,161 +234,12 @@ impl DisplayTransaction {
         } else {
             println!("  Additional Signers: None");
         }
-        if !transaction_message.address_table_lookups.is_empty() {
-            println!("🔍 Address Table Lookups:");
-            for lookup in &transaction_message.address_table_lookups {
-                println!(
-                    "  Account Key: {}, Writable Indexes: {:?}, Readonly Indexes: {:?}",
-                    lookup.account_key, lookup.writable_indexes, lookup.readonly_indexes
-                );
-            }
-        } else {
-            println!("🔍 Address Table Lookups: None");
-        }
-
         let multisig_account = rpc_client.get_account(&multisig_address).await.unwrap();
         println!("Multisig Account:  {}", multisig_account.owner);
 
-        println!("TransactionMessage:");
-        println!(
-            "  Signers: total={}, writable={}, writable_non_signers={}",
-            transaction_message.num_signers,
-            transaction_message.num_writable_signers,
-            transaction_message.num_writable_non_signers,
-        );
-        let account_keys: Vec<Pubkey> = transaction_message.account_keys.clone();
-        println!("🔒 Account Classification:");
-        println!(
-            "  Mutable Signers: {}",
-            format!(
-                "{:?}",
-                &account_keys[..transaction_message.num_writable_signers as usize]
-            )
-            .red()
-        );
-        println!(
-            "  Read-Only Signers: {}",
-            format!(
-                "{:?}",
-                &account_keys[transaction_message.num_writable_signers as usize
-                    ..transaction_message.num_signers as usize]
-            )
-            .yellow()
-        );
-        println!(
-            "  Mutable Non-Signers: {}",
-            format!(
-                "{:?}",
-                &account_keys[transaction_message.num_signers as usize
-                    ..(transaction_message.num_signers
-                        + transaction_message.num_writable_non_signers)
-                        as usize]
-            )
-            .green()
-        );
-        println!(
-            "  Read-Only Non-Signers: {}",
-            format!(
-                "{:?}",
-                &account_keys[(transaction_message.num_signers
-                    + transaction_message.num_writable_non_signers)
-                    as usize..]
-            )
-            .blue()
-        );
-
-        let transaction_message_instructions: Vec<CompiledInstruction> = transaction_message
-            .instructions
-            .iter()
-            .map(convert_to_compiled_instruction)
-            .collect();
-        for (i, instruction) in transaction_message_instructions.iter().enumerate() {
-            let parsed_instruction = parse(
-                &account_keys[instruction.program_id_index as usize],
-                instruction,
-                &AccountKeys::new(&account_keys, None),
-                None,
-            );
-
-            println!("✅ Instruction #{}", i + 1);
-
-            match parsed_instruction {
-                Ok(result) => {
-                    println!("{}", "✅ Proposed Instruction:".green().bold());
-                    println!(
-                        "  {} {}",
-                        "Program:".bright_blue().bold(),
-                        result.program.bright_green()
-                    );
-                    println!(
-                        "  {} {}",
-                        "Program ID:".bright_blue().bold(),
-                        result.program_id.bright_cyan()
-                    );
-
-                    // Pretty-print and colorize the JSON
-                    if let Value::Object(parsed_data) = &result.parsed {
-                        println!("  {} {}", "Parsed Data:".bright_blue().bold(), "{");
-                        for (key, value) in parsed_data {
-                            match key.as_str() {
-                                "type" => {
-                                    println!(
-                                        "    {}: {}",
-                                        key.bright_yellow().bold(),
-                                        value.as_str().unwrap_or("Unknown").bright_green()
-                                    );
-                                }
-                                "info" => {
-                                    if let Value::Object(info) = value {
-                                        println!("    {}: {}", key.bright_yellow().bold(), "{");
-                                        for (info_key, info_value) in info {
-                                            println!(
-                                                "      {}: {}",
-                                                info_key.bright_magenta().bold(),
-                                                info_value.to_string().bright_cyan()
-                                            );
-                                        }
-                                        println!("    {} ", "}");
-                                    }
-                                }
-                                _ => {
-                                    println!(
-                                        "    {}: {}",
-                                        key.bright_yellow().bold(),
-                                        value.to_string().bright_cyan()
-                                    );
-                                }
-                            }
-                        }
-                        println!("  {} ", "}");
-                    } else {
-                        println!(
-                            "  {} {}",
-                            "Parsed Data:".bright_blue().bold(),
-                            result.parsed.to_string().yellow()
-                        );
-                    }
-
-                    // Stack Height
-                    let stack_height = match result.stack_height {
-                        Some(height) => height.to_string().bright_magenta(),
-                        None => "N/A".to_string().yellow(),
-                    };
-                    println!(
-                        "  {} {}",
-                        "Stack Height:".bright_blue().bold(),
-                        stack_height
-                    );
-                }
-                Err(e) => {
-                    eprintln!(
-                        "{} {}",
-                        "❌ Failed to parse instruction:".red().bold(),
-                        e.to_string().yellow()
-                    );
-                }
-            }
-        }
+        load_message_idls(&rpc_client, &transaction_message).await;
+        print_transaction_message(&transaction_message);
+        print_token_amounts(&rpc_client, &transaction_message).await?;
 
         let static_accounts: Vec<Pubkey> = transaction_message.account_keys.clone();
 
@@ -378,9 +362,713 @@ impl DisplayTransaction {
             println!("  {}: {:?}", i + 1, signer_seed);
         }
 
+        output::set(
+            "transaction",
+            json!({
+                "address": transaction_pda.0.to_string(),
+                "account_type": "vault_transaction",
+                "multisig": multisig_address.to_string(),
+                "index": transaction_index,
+                "creator": deserialized_account_data.creator.to_string(),
+                "vault_index": deserialized_account_data.vault_index,
+                "fingerprint": fingerprint_json(&fingerprint),
+                "ephemeral_signers": ephemeral_signer_keys
+                    .iter()
+                    .map(|k| k.to_string())
+                    .collect::<Vec<_>>(),
+                "message": transaction_message_json(&transaction_message),
+                "cpi_calls": cpi_calls
+                    .iter()
+                    .map(|cpi_call| json!({
+                        "program_id": cpi_call.program_id.to_string(),
+                        "accounts": cpi_call
+                            .accounts
+                            .iter()
+                            .map(|meta| json!({
+                                "pubkey": meta.pubkey.to_string(),
+                                "is_signer": meta.is_signer,
+                                "is_writable": meta.is_writable,
+                            }))
+                            .collect::<Vec<_>>(),
+                        "data": hex::encode(&cpi_call.data),
+                    }))
+                    .collect::<Vec<_>>(),
+                "signer_seeds": signer_seeds
+                    .iter()
+                    .map(|seeds| seeds.iter().map(hex::encode).collect::<Vec<_>>())
+                    .collect::<Vec<_>>(),
+            }),
+        );
+
         Ok(())
     }
 }
+
+/// Prints each config action against the multisig state it applies to, e.g. "Change threshold 3 → 2".
+pub fn print_config_transaction(config_transaction: &ConfigTransaction, multisig: &Multisig) {
+    println!(
+        "Transaction is proposed by: {}",
+        config_transaction.creator.to_string().bright_green()
+    );
+    if config_transaction.index <= multisig.stale_transaction_index {
+        println!(
+            "{}",
+            "⚠️ WARNING: The transaction is stale, config transactions can no longer execute once the multisig changed."
+                .red()
+        );
+    }
+    println!("Config Actions:");
+    let mut current = multisig.clone();
+    for (i, action) in config_transaction.actions.iter().enumerate() {
+        let description = match action {
+            ConfigAction::ChangeThreshold { new_threshold } => {
+                format!("Change threshold {} → {}", current.threshold, new_threshold)
+            }
+            ConfigAction::SetTimeLock { new_time_lock } => {
+                format!("Set time lock {}s → {}s", current.time_lock, new_time_lock)
+            }
+            ConfigAction::SetRentCollector { new_rent_collector } => format!(
+                "Set rent collector {} → {}",
+                current
+                    .rent_collector
+                    .map(|k| k.to_string())
+                    .unwrap_or_else(|| "None".to_string()),
+                new_rent_collector
+                    .map(|k| k.to_string())
+                    .unwrap_or_else(|| "None".to_string())
+            ),
+            _ => describe_config_action(action),
+        };
+        println!("  {}: {}", i + 1, description.bright_cyan());
+        match apply_config_actions(&current, std::slice::from_ref(action)) {
+            Ok(next) => current = next,
+            Err(e) => println!("     {}", format!("⚠️ {}", e).red()),
+        }
+    }
+    println!();
+    println!("Resulting configuration (against the current multisig):");
+    print_multisig_change(multisig, &current);
+}
+
+/// The message of a vault (batch) transaction with its decoded instructions, for `--output json`.
+pub fn transaction_message_json(transaction_message: &VaultTransactionMessage) -> Value {
+    let account_keys = &transaction_message.account_keys;
+    let instructions: Vec<Value> = transaction_message
+        .instructions
+        .iter()
+        .map(|instruction| {
+            let compiled_instruction = convert_to_compiled_instruction(instruction);
+            let program_id = account_keys.get(usize::from(instruction.program_id_index));
+            let parsed = program_id.and_then(|program_id| {
+                parse(
+                    program_id,
+                    &compiled_instruction,
+                    &AccountKeys::new(account_keys, None),
+                    None,
+                )
+                .ok()
+            });
+            let decoded = match parsed {
+                Some(_) => None,
+                None => decode_with_idl(account_keys, &compiled_instruction),
+            };
+            let decode_error = match &decoded {
+                Some(Err(e)) => Some(e.to_string()),
+                _ => None,
+            };
+            let decoded = decoded.and_then(Result::ok);
+            json!({
+                "program_id": program_id.map(|k| k.to_string()),
+                "account_indexes": instruction.account_indexes,
+                "data": hex::encode(&instruction.data),
+                "program": parsed.as_ref().map(|p| p.program.clone()),
+                "parsed": parsed.map(|p| p.parsed),
+                "decode_error": decode_error,
+                "decoded": decoded.map(|(decoded, accounts)| json!({
+                    "program": decoded.program_name,
+                    "idl_source": decoded.idl_source.describe(),
+                    "instruction": decoded.instruction_name,
+                    "accounts": accounts
+                        .iter()
+                        .map(|account| json!({
+                            "pubkey": account.to_string(),
+                            "name": decoded.accounts.get(account),
+                        }))
+                        .collect::<Vec<_>>(),
+                    "match": decoded.confidence.to_string(),
+                    "trailing_data": hex::encode(&decoded.trailing_data),
+                    "warnings": decoded.warnings(),
+                    "args": decoded.decoded_args,
+                    "pda_checks": decoded.pda_checks.iter().map(|check| json!({
+                        "account": check.account,
+                        "pubkey": check.pubkey.to_string(),
+                        "status": match &check.status {
+                            PdaStatus::Verified => "verified",
+                            PdaStatus::Mismatch { .. } => "mismatch",
+                            PdaStatus::Unverifiable(_) => "unverifiable",
+                        },
+                    })).collect::<Vec<_>>(),
+                })),
+            })
+        })
+        .collect();
+    json!({
+        "num_signers": transaction_message.num_signers,
+        "num_writable_signers": transaction_message.num_writable_signers,
+        "num_writable_non_signers": transaction_message.num_writable_non_signers,
+        "account_keys": account_keys.iter().map(|k| k.to_string()).collect::<Vec<_>>(),
+        "address_table_lookups": transaction_message
+            .address_table_lookups
+            .iter()
+            .map(|lookup| json!({
+                "account_key": lookup.account_key.to_string(),
+                "writable_indexes": lookup.writable_indexes,
+                "readonly_indexes": lookup.readonly_indexes,
+            }))
+            .collect::<Vec<_>>(),
+        "instructions": instructions,
+    })
+}
+
+/// Prints the signer layout and every decoded instruction of a vault (batch) transaction message.
+pub fn print_transaction_message(transaction_message: &VaultTransactionMessage) {
+    if !transaction_message.address_table_lookups.is_empty() {
+        println!("🔍 Address Table Lookups:");
+        for lookup in &transaction_message.address_table_lookups {
+            println!(
+                "  Account Key: {}, Writable Indexes: {:?}, Readonly Indexes: {:?}",
+                lookup.account_key, lookup.writable_indexes, lookup.readonly_indexes
+            );
+        }
+    } else {
+        println!("🔍 Address Table Lookups: None");
+    }
+
+    println!("TransactionMessage:");
+    println!(
+        "  Signers: total={}, writable={}, writable_non_signers={}",
+        transaction_message.num_signers,
+        transaction_message.num_writable_signers,
+        transaction_message.num_writable_non_signers,
+    );
+    let account_keys: Vec<Pubkey> = transaction_message.account_keys.clone();
+    println!("🔒 Account Classification:");
+    println!(
+        "  Mutable Signers: {}",
+        format!(
+            "{:?}",
+            &account_keys[..transaction_message.num_writable_signers as usize]
+        )
+        .red()
+    );
+    println!(
+        "  Read-Only Signers: {}",
+        format!(
+            "{:?}",
+            &account_keys[transaction_message.num_writable_signers as usize
+                ..transaction_message.num_signers as usize]
+        )
+        .yellow()
+    );
+    println!(
+        "  Mutable Non-Signers: {}",
+        format!(
+            "{:?}",
+            &account_keys[transaction_message.num_signers as usize
+                ..(transaction_message.num_signers + transaction_message.num_writable_non_signers)
+                    as usize]
+        )
+        .green()
+    );
+    println!(
+        "  Read-Only Non-Signers: {}",
+        format!(
+            "{:?}",
+            &account_keys[(transaction_message.num_signers
+                + transaction_message.num_writable_non_signers)
+                as usize..]
+        )
+        .blue()
+    );
+
+    let known: Vec<(Pubkey, String)> = account_keys
+        .iter()
+        .filter_map(|key| address_book::label(key).map(|label| (*key, label)))
+        .collect();
+    if !known.is_empty() {
+        println!("📒 Known Addresses:");
+        for (key, label) in known {
+            println!("  {}: {}", key, label.bright_cyan());
+        }
+    }
+
+    let transaction_message_instructions: Vec<CompiledInstruction> = transaction_message
+        .instructions
+        .iter()
+        .map(convert_to_compiled_instruction)
+        .collect();
+    for (i, instruction) in transaction_message_instructions.iter().enumerate() {
+        let parsed_instruction = parse(
+            &account_keys[instruction.program_id_index as usize],
+            instruction,
+            &AccountKeys::new(&account_keys, None),
+            None,
+        );
+
+        println!("✅ Instruction #{}", i + 1);
+
+        match parsed_instruction {
+            Ok(result) => {
+                println!("{}", "✅ Proposed Instruction:".green().bold());
+                println!(
+                    "  {} {}",
+                    "Program:".bright_blue().bold(),
+                    result.program.bright_green()
+                );
+                println!(
+                    "  {} {}",
+                    "Program ID:".bright_blue().bold(),
+                    result.program_id.bright_cyan()
+                );
+
+                // Pretty-print and colorize the JSON
+                if let Value::Object(parsed_data) = &result.parsed {
+                    println!("  {} {}", "Parsed Data:".bright_blue().bold(), "{");
+                    for (key, value) in parsed_data {
+                        match key.as_str() {
+                            "type" => {
+                                println!(
+                                    "    {}: {}",
+                                    key.bright_yellow().bold(),
+                                    value.as_str().unwrap_or("Unknown").bright_green()
+                                );
+                            }
+                            "info" => {
+                                if let Value::Object(info) = value {
+                                    println!("    {}: {}", key.bright_yellow().bold(), "{");
+                                    for (info_key, info_value) in info {
+                                        println!(
+                                            "      {}: {}",
+                                            info_key.bright_magenta().bold(),
+                                            info_value.to_string().bright_cyan()
+                                        );
+                                    }
+                                    println!("    {} ", "}");
+                                }
+                            }
+                            _ => {
+                                println!(
+                                    "    {}: {}",
+                                    key.bright_yellow().bold(),
+                                    value.to_string().bright_cyan()
+                                );
+                            }
+                        }
+                    }
+                    println!("  {} ", "}");
+                } else {
+                    println!(
+                        "  {} {}",
+                        "Parsed Data:".bright_blue().bold(),
+                        result.parsed.to_string().yellow()
+                    );
+                }
+
+                // Stack Height
+                let stack_height = match result.stack_height {
+                    Some(height) => height.to_string().bright_magenta(),
+                    None => "N/A".to_string().yellow(),
+                };
+                println!(
+                    "  {} {}",
+                    "Stack Height:".bright_blue().bold(),
+                    stack_height
+                );
+            }
+            Err(e) => match decode_with_idl(&account_keys, instruction) {
+                Some(Ok((decoded, accounts))) => print_decoded_instruction(&decoded, &accounts),
+                Some(Err(decode_error)) => {
+                    println!(
+                        "{} {}",
+                        "❌ SECURITY WARNING: Not decoded with the IDL:"
+                            .red()
+                            .bold(),
+                        decode_error.to_string().yellow()
+                    );
+                    output::push("warnings", json!(decode_error.to_string()));
+                }
+                None => {
+                    eprintln!(
+                        "{} {}",
+                        "❌ Failed to parse instruction:".red().bold(),
+                        e.to_string().yellow()
+                    );
+                }
+            },
+        }
+    }
+}
+
+/// Fetches the on-chain Anchor IDL of every program in the message `parse` does not know and no
+/// IDL is registered for, so [`print_transaction_message`] can decode its instructions.
+pub async fn load_message_idls(
+    rpc_client: &RpcClient,
+    transaction_message: &VaultTransactionMessage,
+) {
+    let account_keys = &transaction_message.account_keys;
+    let mut program_ids = Vec::new();
+    for instruction in &transaction_message.instructions {
+        let Some(&program_id) = account_keys.get(usize::from(instruction.program_id_index)) else {
+            continue;
+        };
+        let parsed = parse(
+            &program_id,
+            &convert_to_compiled_instruction(instruction),
+            &AccountKeys::new(account_keys, None),
+            None,
+        );
+        if parsed.is_err() && !program_ids.contains(&program_id) {
+            program_ids.push(program_id);
+        }
+    }
+    load_onchain_idls(rpc_client, &program_ids).await;
+}
+
+/// Decodes an instruction `parse` does not know with the IDL registered for its program, returns
+/// the decoded instruction and its accounts in order. `None` when no IDL is registered for it.
+fn decode_with_idl(
+    account_keys: &[Pubkey],
+    instruction: &CompiledInstruction,
+) -> Option<Result<(InstructionMapResult, Vec<Pubkey>), DecodeError>> {
+    let program_id = *account_keys.get(usize::from(instruction.program_id_index))?;
+    // Accounts loaded from address lookup tables are not resolved here, their names would shift.
+    let accounts = instruction
+        .accounts
+        .iter()
+        .map(|&index| account_keys.get(usize::from(index)).copied())
+        .collect::<Option<Vec<_>>>()?;
+    let my_instruction = MyInstruction {
+        program_id_key: program_id,
+        account_keys: accounts.clone(),
+        instruction_data: instruction.data.clone(),
+    };
+    let registry = idl_registry();
+    registry.get(&program_id)?;
+    Some(
+        map_instruction(
+            &my_instruction,
+            &registry,
+            &chainparser::borsh::BorshDeserializer,
+        )
+        .map(|decoded| (decoded, accounts)),
+    )
+}
+
+fn print_decoded_instruction(decoded: &InstructionMapResult, accounts: &[Pubkey]) {
+    if decoded.idl_source.is_trusted() {
+        println!(
+            "{}",
+            "✅ Proposed Instruction (decoded with IDL):".green().bold()
+        );
+    } else {
+        println!(
+            "{}",
+            "⚠️ Proposed Instruction (decoded with an unverified IDL):"
+                .yellow()
+                .bold()
+        );
+    }
+    println!(
+        "  {} {} ({})",
+        "Program:".bright_blue().bold(),
+        decoded
+            .program_name
+            .as_deref()
+            .unwrap_or("Unknown")
+            .bright_green(),
+        decoded.program_id
+    );
+    print_idl_source(decoded.idl_source);
+    println!(
+        "  {} {}",
+        "Instruction:".bright_blue().bold(),
+        decoded
+            .instruction_name
+            .as_deref()
+            .unwrap_or("Unknown")
+            .bright_yellow()
+            .bold()
+    );
+    println!("  {}", "Accounts:".bright_blue().bold());
+    for account in accounts {
+        let name = decoded.accounts.get(account).map_or("?", String::as_str);
+        match address_book::label(account) {
+            Some(label) => println!(
+                "    {}: {} 📒 {}",
+                name.bright_magenta().bold(),
+                account.to_string().bright_cyan(),
+                label.bright_cyan()
+            ),
+            None => println!(
+                "    {}: {}",
+                name.bright_magenta().bold(),
+                account.to_string().bright_cyan()
+            ),
+        }
+    }
+    println!(
+        "  {} {}",
+        "Args:".bright_blue().bold(),
+        serde_json::to_string_pretty(&decoded.decoded_args)
+            .unwrap_or_default()
+            .bright_cyan()
+    );
+    print_pda_checks(&decoded.pda_checks);
+    print_decode_warnings(decoded);
+}
+
+/// Prints the transferred and approved amounts of the SOL and token instructions with the decimals
+/// and symbol of their mint, raw base units alone are easy to misread by orders of magnitude.
+pub async fn print_token_amounts(
+    rpc_client: &RpcClient,
+    transaction_message: &VaultTransactionMessage,
+) -> eyre::Result<()> {
+    let token_2022 = Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap();
+    let account_keys = &transaction_message.account_keys;
+    // Accounts loaded from address lookup tables are not resolved here.
+    let account = |instruction: &MultisigCompiledInstruction, position: usize| {
+        instruction
+            .account_indexes
+            .get(position)
+            .and_then(|&index| account_keys.get(usize::from(index)))
+            .copied()
+    };
+
+    let mut amounts = Vec::new();
+    for (i, instruction) in transaction_message.instructions.iter().enumerate() {
+        let Some(&program_id) = account_keys.get(usize::from(instruction.program_id_index)) else {
+            continue;
+        };
+        if program_id == solana_sdk::system_program::id() {
+            if let Ok(SystemInstruction::Transfer { lamports }) =
+                limited_deserialize::<SystemInstruction>(&instruction.data, 1232)
+            {
+                let recipient = account(instruction, 1);
+                amounts.push((
+                    i,
+                    "Transfer",
+                    Pubkey::default(),
+                    Some(MintInfo::sol()),
+                    lamports,
+                    None,
+                    recipient,
+                    false,
+                ));
+            }
+            continue;
+        }
+        if program_id != spl_token::id() && program_id != token_2022 {
+            continue;
+        }
+        // (kind, amount, mint, decimals, position of the receiving account)
+        let (kind, amount, mint, decimals, to) = match TokenInstruction::unpack(&instruction.data) {
+            Ok(TokenInstruction::Transfer { amount }) => ("Transfer", amount, None, None, Some(1)),
+            Ok(TokenInstruction::Approve { amount }) => ("Approve", amount, None, None, Some(1)),
+            Ok(TokenInstruction::TransferChecked { amount, decimals }) => (
+                "Transfer",
+                amount,
+                account(instruction, 1),
+                Some(decimals),
+                Some(2),
+            ),
+            Ok(TokenInstruction::ApproveChecked { amount, decimals }) => (
+                "Approve",
+                amount,
+                account(instruction, 1),
+                Some(decimals),
+                Some(2),
+            ),
+            Ok(TokenInstruction::MintTo { amount }) => {
+                ("Mint", amount, account(instruction, 0), None, Some(1))
+            }
+            Ok(TokenInstruction::Burn { amount }) => {
+                ("Burn", amount, account(instruction, 1), None, None)
+            }
+            _ => continue,
+        };
+        // Unchecked transfers and approvals only name the source token account.
+        let mint = match mint {
+            Some(mint) => Some(mint),
+            None => match account(instruction, 0) {
+                Some(source) => fetch_token_account(rpc_client, &source)
+                    .await
+                    .map(|account| account.mint),
+                None => None,
+            },
+        };
+        let Some(mint) = mint else {
+            println!(
+                "  Instruction #{}: {} {} base units of an unknown mint",
+                i + 1,
+                kind,
+                amount
+            );
+            continue;
+        };
+        // A review must not abort on a mint it cannot read, the raw amount is still shown.
+        let mint_info = match fetch_mint_info(rpc_client, &mint).await {
+            Ok(mint_info) => Some(mint_info),
+            Err(e) => {
+                let warning = format!(
+                    "Instruction #{}: decimals of {} not loaded, the amount is in base units: {}",
+                    i + 1,
+                    mint,
+                    e
+                );
+                println!("  {}", format!("⚠️ {}", warning).yellow());
+                output::push("warnings", json!(warning));
+                None
+            }
+        };
+        // Delegates are wallets, token accounts are labelled by their owner.
+        let mut recipient = to.and_then(|position| account(instruction, position));
+        let mut owner_unresolved = false;
+        if let Some(token_account) =
+            recipient.filter(|key| kind != "Approve" && !address_book::is_known(key))
+        {
+            // The recipient's associated token account may only be created by this message.
+            let owner = match fetch_token_account(rpc_client, &token_account).await {
+                Some(account) => Some(account.owner),
+                None => created_associated_token_account_owner(transaction_message, &token_account),
+            };
+            match owner {
+                Some(owner) => recipient = Some(owner),
+                None => owner_unresolved = true,
+            }
+        }
+        amounts.push((
+            i,
+            kind,
+            mint,
+            mint_info,
+            amount,
+            decimals,
+            recipient,
+            owner_unresolved,
+        ));
+    }
+
+    if amounts.is_empty() {
+        return Ok(());
+    }
+    println!("💰 Amounts:");
+    for (i, kind, mint, mint_info, amount, decimals, recipient, owner_unresolved) in amounts {
+        let display = match &mint_info {
+            Some(mint_info) => mint_info.display(amount),
+            None => format!("{} base units of mint {}", amount, mint),
+        };
+        println!(
+            "  Instruction #{}: {} {}",
+            i + 1,
+            kind,
+            display.bright_cyan()
+        );
+        if let Some(recipient) = &recipient {
+            println!("    To: {}", address_book::describe(recipient));
+            if owner_unresolved {
+                println!(
+                    "    {}",
+                    "⚠️ This is a token account whose owner could not be resolved, the wallet receiving the tokens is unknown."
+                        .yellow()
+                );
+            }
+            address_book::warn_unknown_recipient(recipient);
+        }
+        if let (Some(decimals), Some(mint_info)) = (decimals, &mint_info)
+            && decimals != mint_info.decimals
+        {
+            println!(
+                "    {}",
+                format!(
+                    "⚠️ The instruction claims {} decimals, the mint has {}, it will fail.",
+                    decimals, mint_info.decimals
+                )
+                .red()
+            );
+        }
+        output::push(
+            "amounts",
+            json!({
+                "instruction": i + 1,
+                "kind": kind,
+                "mint": mint.to_string(),
+                "symbol": mint_info.as_ref().and_then(|mint_info| mint_info.symbol.clone()),
+                "decimals": mint_info.as_ref().map(|mint_info| mint_info.decimals),
+                "amount": amount.to_string(),
+                "ui_amount": mint_info
+                    .as_ref()
+                    .map(|mint_info| format_ui_amount(amount, mint_info.decimals)),
+                "recipient": recipient.map(|k| k.to_string()),
+                "recipient_label": recipient.as_ref().and_then(address_book::label),
+                "recipient_owner_unresolved": owner_unresolved,
+            }),
+        );
+    }
+    Ok(())
+}
+
+/// A SPL Token or Token-2022 account, `None` if it does not exist (yet).
+/// The token account at `token_account`, `None` if it does not exist or is not owned by the SPL
+/// Token or Token-2022 program.
+async fn fetch_token_account(
+    rpc_client: &RpcClient,
+    token_account: &Pubkey,
+) -> Option<spl_token::state::Account> {
+    let account = rpc_client.get_account(token_account).await.ok()?;
+    let token_2022 = Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap();
+    if account.owner != spl_token::id() && account.owner != token_2022 {
+        return None;
+    }
+    let base = account.data.get(..spl_token::state::Account::LEN)?;
+    spl_token::state::Account::unpack_from_slice(base).ok()
+}
+
+/// The wallet of `token_account` when the message creates it as an associated token account, the
+/// address is re-derived so the instruction cannot name a wallet the account does not belong to.
+fn created_associated_token_account_owner(
+    transaction_message: &VaultTransactionMessage,
+    token_account: &Pubkey,
+) -> Option<Pubkey> {
+    let account_keys = &transaction_message.account_keys;
+    transaction_message
+        .instructions
+        .iter()
+        .find_map(|instruction| {
+            let program_id = account_keys.get(usize::from(instruction.program_id_index))?;
+            // Create and CreateIdempotent, the same accounts.
+            if *program_id != spl_associated_token_account::id()
+                || !matches!(instruction.data.as_slice(), [] | [0] | [1])
+            {
+                return None;
+            }
+            let account = |position: usize| {
+                instruction
+                    .account_indexes
+                    .get(position)
+                    .and_then(|&index| account_keys.get(usize::from(index)))
+            };
+            let (ata, wallet, mint, token_program) =
+                (account(1)?, account(2)?, account(3)?, account(5)?);
+            let derived =
+                spl_associated_token_account::get_associated_token_address_with_program_id(
+                    wallet,
+                    mint,
+                    token_program,
+                );
+            (ata == token_account && derived == *token_account).then_some(*wallet)
+        })
+}
+
 pub fn derive_ephemeral_signers_offchain(
     transaction_key: Pubkey,
     ephemeral_signer_bumps: &[u8],
@@ -1,3 +1,4 @@
+use serde_json::json;
 use solana_sdk::pubkey::Pubkey;
 use squads_multisig::pda::get_vault_pda;
 use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
@@ -5,6 +6,8 @@ use std::str::FromStr;
 
 use clap::Args;
 
+use crate::output;
+
 #[derive(Args)]
 pub struct DisplayVault {
     /// RPC URL
@@ -51,10 +54,25 @@ impl DisplayVault {
         let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
         let rpc_client = RpcClient::new(rpc_url.to_string());
 
-        match rpc_client.get_balance(&vault_address.0).await {
-            Ok(balance) => println!("Vault SOL Balance: {} lamports", balance),
-            Err(_) => println!("Vault does not exist or has no SOL balance."),
-        }
+        let balance = match rpc_client.get_balance(&vault_address.0).await {
+            Ok(balance) => {
+                println!("Vault SOL Balance: {} lamports", balance);
+                Some(balance)
+            }
+            Err(_) => {
+                println!("Vault does not exist or has no SOL balance.");
+                None
+            }
+        };
+        output::set(
+            "vault",
+            json!({
+                "address": vault_address.0.to_string(),
+                "multisig": multisig_address.to_string(),
+                "vault_index": vault_index,
+                "lamports": balance,
+            }),
+        );
 
         Ok(())
     }
@@ -0,0 +1,213 @@
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::{Value, json};
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+
+use crate::output;
+use crate::squads_decoder::{IdlRegistry, fetch_idl, import_idl, remove_idl};
+
+/// IDLs used to decode the instructions of other programs proposed through the multisig
+#[derive(Args)]
+pub struct Idl {
+    #[command(subcommand)]
+    command: IdlCommand,
+}
+
+#[derive(Subcommand)]
+pub enum IdlCommand {
+    Import(IdlImport),
+    Fetch(IdlFetch),
+    Remove(IdlRemove),
+    List(IdlList),
+}
+
+impl Idl {
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            IdlCommand::Import(command) => command.execute(),
+            IdlCommand::Fetch(command) => command.execute().await,
+            IdlCommand::Remove(command) => command.execute(),
+            IdlCommand::List(command) => command.execute(),
+        }
+    }
+}
+
+fn registry_dir(dir: Option<String>) -> PathBuf {
+    dir.map(PathBuf::from)
+        .unwrap_or_else(IdlRegistry::default_dir)
+}
+
+#[derive(Args)]
+pub struct IdlImport {
+    /// Directory of the IDL registry, defaults to $SECURE_SQUADS_IDL_DIR or ~/.config/secure-squads/idls
+    #[arg(long)]
+    dir: Option<String>,
+
+    /// Path of the Anchor or Shank IDL JSON file
+    #[arg(long)]
+    path: String,
+
+    /// Program the IDL describes, defaults to the address declared in the IDL
+    #[arg(long)]
+    program_id: Option<String>,
+}
+
+impl IdlImport {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self {
+            dir,
+            path,
+            program_id,
+        } = self;
+
+        let dir = registry_dir(dir);
+        let program_id =
+            program_id.map(|program_id| Pubkey::from_str(&program_id).expect("Invalid program ID"));
+
+        let (program_id, idl) = import_idl(&dir, &PathBuf::from(path), program_id)?;
+
+        output::set("program_id", json!(program_id.to_string()));
+        output::set("name", json!(idl.idl.name));
+        output::set("instructions", json!(idl.idl.instructions.len()));
+        println!(
+            "{}",
+            format!(
+                "✅ Imported the IDL of {} ({} instructions) for {} to {}",
+                idl.idl.name,
+                idl.idl.instructions.len(),
+                program_id,
+                dir.display()
+            )
+            .green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct IdlFetch {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Directory of the IDL registry, defaults to $SECURE_SQUADS_IDL_DIR or ~/.config/secure-squads/idls
+    #[arg(long)]
+    dir: Option<String>,
+
+    /// Anchor program whose on-chain IDL to fetch, replaces the cached one
+    #[arg(long)]
+    program_id: String,
+}
+
+impl IdlFetch {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            dir,
+            program_id,
+        } = self;
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let dir = registry_dir(dir);
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let rpc_client = RpcClient::new(rpc_url);
+        let onchain = fetch_idl(&rpc_client, &dir, &program_id)
+            .await?
+            .ok_or_else(|| eyre!("{} has no Anchor IDL account", program_id))?;
+
+        output::set("program_id", json!(program_id.to_string()));
+        output::set("idl_address", json!(onchain.idl_address.to_string()));
+        output::set("slot", json!(onchain.slot));
+        output::set("name", json!(onchain.idl.idl.name));
+        println!(
+            "{}",
+            format!(
+                "✅ Fetched the IDL of {} ({} instructions) from {} at slot {}",
+                onchain.idl.idl.name,
+                onchain.idl.idl.instructions.len(),
+                onchain.idl_address,
+                onchain.slot
+            )
+            .green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct IdlRemove {
+    /// Directory of the IDL registry, defaults to $SECURE_SQUADS_IDL_DIR or ~/.config/secure-squads/idls
+    #[arg(long)]
+    dir: Option<String>,
+
+    /// Program whose imported and cached IDL to remove
+    #[arg(long)]
+    program_id: String,
+}
+
+impl IdlRemove {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self { dir, program_id } = self;
+
+        let dir = registry_dir(dir);
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        remove_idl(&dir, &program_id)?;
+
+        output::set("program_id", json!(program_id.to_string()));
+        println!(
+            "{}",
+            format!(
+                "✅ Removed the IDL of {} from {}",
+                program_id,
+                dir.display()
+            )
+            .green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct IdlList {
+    /// Directory of the IDL registry, defaults to $SECURE_SQUADS_IDL_DIR or ~/.config/secure-squads/idls
+    #[arg(long)]
+    dir: Option<String>,
+}
+
+impl IdlList {
+    pub fn execute(self) -> eyre::Result<()> {
+        let Self { dir } = self;
+
+        let dir = registry_dir(dir);
+        let registry = IdlRegistry::load(&dir)?;
+
+        println!("IDL Registry -> {}", dir.display());
+        let mut programs = Vec::new();
+        for (program_id, idl, source) in registry.programs() {
+            println!(
+                "  {}  {} ({} instructions, {} accounts, {})",
+                program_id,
+                idl.idl.name.bright_cyan(),
+                idl.idl.instructions.len(),
+                idl.account_discriminators.len(),
+                source
+            );
+            programs.push(json!({
+                "program_id": program_id.to_string(),
+                "name": idl.idl.name,
+                "instructions": idl.idl.instructions.len(),
+                "accounts": idl.account_discriminators.len(),
+                "source": source.to_string(),
+            }));
+        }
+        output::set("programs", Value::Array(programs));
+        Ok(())
+    }
+}
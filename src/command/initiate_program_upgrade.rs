@@ -1,6 +1,10 @@
 use std::str::FromStr;
 use std::time::Duration;
 
+use crate::fingerprint::{
+    compile_vault_message, format_fingerprint, vault_transaction_fingerprint,
+};
+use crate::transaction_buffer::{BufferedVaultTransaction, exceeds_transaction_size};
 use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
 use clap::Args;
 use colored::Colorize;
@@ -57,6 +61,10 @@ pub struct InitiateProgramUpgrade {
     #[arg(long)]
     memo: Option<String>,
 
+    /// Create the proposal as a Draft, voting only opens once it is activated
+    #[arg(long, default_value_t = false)]
+    draft: bool,
+
     /// The program to upgrade
     #[arg(long)]
     program_to_upgrade_id: String,
@@ -77,6 +85,7 @@ impl InitiateProgramUpgrade {
             keypair,
             multisig_pubkey,
             memo,
+            draft,
             vault_index,
             priority_fee_lamports,
             buffer_address,
@@ -123,10 +132,34 @@ impl InitiateProgramUpgrade {
         println!("⚙️ Config Parameters");
         println!("Multisig Key:       {}", multisig_pubkey);
         println!("Transaction Index:       {}", transaction_index);
+        if draft {
+            println!(
+                "Proposal Status:       {}",
+                "Draft, open voting with `proposal-vote --action activate`".yellow()
+            );
+        }
         println!("Vault Index:       {}", vault_index);
         println!("To upgrade program ID:       {}", program_to_upgrade_id);
         println!("Buffer Address:       {}", buffer_address);
         println!("Spill Address:       {}", spill_address);
+
+        let vault_pda = get_vault_pda(&multisig, vault_index, Some(&program_id));
+        let upgrade_program_message = program_upgrade_message(
+            &vault_pda.0,
+            &program_to_upgrade,
+            &buffer_address_id,
+            &spill_address_id,
+        );
+        let fingerprint = vault_transaction_fingerprint(
+            &multisig,
+            transaction_index,
+            vault_index,
+            &compile_vault_message(upgrade_program_message.clone())?,
+        );
+        println!(
+            "Fingerprint:       {}",
+            format_fingerprint(&fingerprint).bright_green().bold()
+        );
         println!();
 
         let proceed = Confirm::new()
@@ -147,24 +180,31 @@ impl InitiateProgramUpgrade {
             .await
             .expect("Failed to get blockhash");
 
-        let vault_pda = get_vault_pda(&multisig, vault_index, Some(&program_id));
-
-        let instruction = upgrade(
-            &program_to_upgrade, // program id
-            &buffer_address_id,
-            &vault_pda.0,      // in this case vault has the authority to update the code
-            &spill_address_id, // Any excess lamports  from the program account are transferred to a spill address during the upgrade.
-        );
-
-        let upgrade_program_message =
-            TransactionMessage::try_compile(&vault_pda.0, &[instruction], &[]).unwrap();
-
         //  upgrade_program_message.sanitize();
         //  upgrade_program_message
 
         //  let serialized_message = upgrade_program_message.serialize();
         //   println!("Serialized Message Length: {}", serialized_message.len());
         // println!("Serialized Message: {:?}", serialized_message);
+        let proposal_create_instruction = Instruction {
+            accounts: ProposalCreateAccounts {
+                creator: transaction_creator,
+                rent_payer: transaction_creator,
+                proposal: proposal_pda.0,
+                multisig,
+                system_program: solana_sdk::system_program::id(),
+            }
+            .to_account_metas(Some(false)),
+            data: ProposalCreateData {
+                args: ProposalCreateArgs {
+                    draft,
+                    transaction_index,
+                },
+            }
+            .data(),
+            program_id,
+        };
+
         let message = Message::try_compile(
             &transaction_creator,
             &[
@@ -184,31 +224,14 @@ impl InitiateProgramUpgrade {
                         args: VaultTransactionCreateArgs {
                             ephemeral_signers: 0,
                             vault_index,
-                            memo,
+                            memo: memo.clone(),
                             transaction_message: upgrade_program_message.try_to_vec().unwrap(), // upgrade_program_message
                         },
                     }
                     .data(),
                     program_id,
                 },
-                Instruction {
-                    accounts: ProposalCreateAccounts {
-                        creator: transaction_creator,
-                        rent_payer: transaction_creator,
-                        proposal: proposal_pda.0,
-                        multisig,
-                        system_program: solana_sdk::system_program::id(),
-                    }
-                    .to_account_metas(Some(false)),
-                    data: ProposalCreateData {
-                        args: ProposalCreateArgs {
-                            draft: false,
-                            transaction_index,
-                        },
-                    }
-                    .data(),
-                    program_id,
-                },
+                proposal_create_instruction.clone(),
             ],
             &[],
             blockhash,
@@ -217,11 +240,34 @@ impl InitiateProgramUpgrade {
         let message_hex = hex::encode(message.serialize());
 
         println!("Hex message: {}", message_hex);
-        let transaction = VersionedTransaction::try_new(
-            VersionedMessage::V0(message),
-            &[&*transaction_creator_keypair],
-        )
-        .expect("Failed to create transaction");
+        let message = VersionedMessage::V0(message);
+        if exceeds_transaction_size(&message, 1) {
+            progress.finish_and_clear();
+            let signature = BufferedVaultTransaction {
+                multisig,
+                transaction_index,
+                vault_index,
+                ephemeral_signers: 0,
+                memo,
+                transaction_message: upgrade_program_message.try_to_vec().unwrap(),
+                program_id,
+            }
+            .send(
+                rpc_client,
+                &*transaction_creator_keypair,
+                &[proposal_create_instruction],
+                priority_fee_lamports.unwrap_or(200_000),
+            )
+            .await?;
+            println!(
+                "✅ Transaction created successfully. Signature: {}",
+                signature.green()
+            );
+            return Ok(());
+        }
+
+        let transaction = VersionedTransaction::try_new(message, &[&*transaction_creator_keypair])
+            .expect("Failed to create transaction");
         transaction_details(&transaction);
 
         let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
@@ -234,3 +280,19 @@ impl InitiateProgramUpgrade {
         Ok(())
     }
 }
+
+/// The vault transaction message upgrading `program` from `buffer`, `verify-proposal` rebuilds
+/// it with the same function.
+pub fn program_upgrade_message(
+    vault: &Pubkey,
+    program: &Pubkey,
+    buffer: &Pubkey,
+    spill: &Pubkey,
+) -> TransactionMessage {
+    let instruction = upgrade(
+        program, // program id
+        buffer, vault, // in this case vault has the authority to update the code
+        spill, // Any excess lamports  from the program account are transferred to a spill address during the upgrade.
+    );
+    TransactionMessage::try_compile(vault, &[instruction], &[]).unwrap()
+}
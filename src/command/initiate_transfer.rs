@@ -1,6 +1,7 @@
 use clap::Args;
 use colored::Colorize;
 use dialoguer::Confirm;
+use eyre::eyre;
 use indicatif::ProgressBar;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
 use solana_sdk::instruction::CompiledInstruction as CompiledInstruction_x;
@@ -18,7 +19,6 @@ use std::str::FromStr;
 use std::time::Duration;
 
 use spl_associated_token_account::get_associated_token_address_with_program_id;
-use spl_token::instruction::transfer;
 use squads_multisig::anchor_lang::{AnchorSerialize, InstructionData};
 use squads_multisig::client::get_multisig;
 use squads_multisig::pda::{get_proposal_pda, get_transaction_pda, get_vault_pda};
@@ -35,6 +35,14 @@ use squads_multisig::squads_multisig_program::{
 };
 use squads_multisig::vault_transaction::VaultTransactionMessageExt;
 
+use crate::address_book::{describe, warn_unknown_recipient};
+use crate::fingerprint::{
+    compile_vault_message, format_fingerprint, vault_transaction_fingerprint,
+};
+use crate::token_amount::{
+    MintInfo, fetch_mint_info, parse_ui_amount, transfer_checked_instruction,
+};
+use crate::transaction_buffer::{BufferedVaultTransaction, exceeds_transaction_size};
 use crate::utils::{
     create_signer_from_path, extract_transaction_message, send_and_confirm_transaction,
     transaction_details,
@@ -50,7 +58,7 @@ pub struct InitiateTransfer {
     #[arg(long)]
     program_id: Option<String>,
 
-    /// Token program ID. Defaults to regular SPL.
+    /// Token program ID. Defaults to the owner of the mint.
     #[arg(long)]
     token_program_id: Option<String>,
     //So11111111111111111111111111111111111111112
@@ -58,8 +66,13 @@ pub struct InitiateTransfer {
     #[arg(long)]
     token_mint_address: String,
 
+    /// Amount in base units of the mint
+    #[arg(long, conflicts_with = "amount", required_unless_present = "amount")]
+    token_amount_u64: Option<u64>,
+
+    /// Amount in tokens, e.g. 12.5, converted with the decimals of the mint
     #[arg(long)]
-    token_amount_u64: u64,
+    amount: Option<String>,
 
     /// The recipient of the Token(s)
     #[arg(long)]
@@ -80,6 +93,10 @@ pub struct InitiateTransfer {
     #[arg(long)]
     memo: Option<String>,
 
+    /// Create the proposal as a Draft, voting only opens once it is activated
+    #[arg(long, default_value_t = false)]
+    draft: bool,
+
     #[arg(long)]
     priority_fee_lamports: Option<u64>,
 }
@@ -93,9 +110,11 @@ impl InitiateTransfer {
             keypair,
             multisig_pubkey,
             memo,
+            draft,
             vault_index,
             priority_fee_lamports,
             token_amount_u64,
+            amount,
             token_mint_address,
             recipient,
         } = self;
@@ -103,12 +122,7 @@ impl InitiateTransfer {
         let program_id =
             program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
 
-        let token_program_id = token_program_id
-            .unwrap_or_else(|| "TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA".to_string());
-
         let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
-        let token_program_id: Pubkey =
-            Pubkey::from_str(&token_program_id).expect("Invalid program ID");
 
         let transaction_creator_keypair = create_signer_from_path(keypair).unwrap();
 
@@ -124,6 +138,24 @@ impl InitiateTransfer {
 
         let token_mint = Pubkey::from_str(&token_mint_address).expect("Invalid Token Mint Address");
 
+        let mint_info = fetch_mint_info(rpc_client, &token_mint).await?;
+        if let Some(expected) = token_program_id {
+            let expected = Pubkey::from_str(&expected).expect("Invalid token program ID");
+            if expected != mint_info.token_program {
+                return Err(eyre!(
+                    "The mint is owned by {}, not by the token program {}",
+                    mint_info.token_program,
+                    expected
+                ));
+            }
+        }
+        let token_program_id = mint_info.token_program;
+        let token_amount = match (token_amount_u64, amount) {
+            (Some(token_amount_u64), _) => token_amount_u64,
+            (None, Some(amount)) => parse_ui_amount(&amount, mint_info.decimals)?,
+            (None, None) => return Err(eyre!("Either --amount or --token-amount-u64 is required")),
+        };
+
         let multisig_data = get_multisig(rpc_client, &multisig).await?;
 
         let transaction_index = multisig_data.transaction_index + 1;
@@ -143,7 +175,42 @@ impl InitiateTransfer {
         println!("⚙️ Config Parameters");
         println!("Multisig Key:       {}", multisig_pubkey);
         println!("Transaction Index:       {}", transaction_index);
+        if draft {
+            println!(
+                "Proposal Status:       {}",
+                "Draft, open voting with `proposal-vote --action activate`".yellow()
+            );
+        }
         println!("Vault Index:       {}", vault_index);
+        println!(
+            "Token Mint:        {}{}",
+            token_mint,
+            mint_info
+                .name
+                .as_ref()
+                .map(|name| format!(" ({})", name))
+                .unwrap_or_default()
+        );
+        println!("Recipient:         {}", describe(&recipient_pubkey));
+        println!(
+            "Amount:            {}",
+            mint_info.display(token_amount).bright_cyan()
+        );
+        warn_unknown_recipient(&recipient_pubkey);
+
+        let vault_pda = get_vault_pda(&multisig, vault_index, Some(&program_id));
+        let transaction_message =
+            transfer_message(&vault_pda.0, &mint_info, &recipient_pubkey, token_amount);
+        let fingerprint = vault_transaction_fingerprint(
+            &multisig,
+            transaction_index,
+            vault_index,
+            &compile_vault_message(transaction_message.clone())?,
+        );
+        println!(
+            "Fingerprint:       {}",
+            format_fingerprint(&fingerprint).bright_green().bold()
+        );
         println!();
 
         let proceed = Confirm::new()
@@ -164,7 +231,6 @@ impl InitiateTransfer {
             .await
             .expect("Failed to get blockhash");
 
-        let vault_pda = get_vault_pda(&multisig, vault_index, Some(&program_id));
         println!("Vault PDA: {:?}", vault_pda.0);
         let sender_ata = get_associated_token_address_with_program_id(
             &vault_pda.0,
@@ -241,23 +307,27 @@ impl InitiateTransfer {
                 signature
             );
         }
-        println!("Token Amount: {:?}", token_amount_u64);
+        println!("Token Amount: {}", mint_info.display(token_amount));
         println!("Authority pubkey: {:?}", &vault_pda.0);
 
-        let transaction_message = TransactionMessage::try_compile(
-            &vault_pda.0,
-            &[transfer(
-                &token_program_id,
-                &sender_ata,
-                &recipient_ata,
-                &vault_pda.0,
-                &[&vault_pda.0],
-                token_amount_u64,
-            )
-            .unwrap()],
-            &[],
-        )
-        .unwrap();
+        let proposal_create_instruction = Instruction {
+            accounts: ProposalCreateAccounts {
+                creator: transaction_creator,
+                rent_payer: transaction_creator,
+                proposal: proposal_pda.0,
+                multisig,
+                system_program: solana_sdk::system_program::id(),
+            }
+            .to_account_metas(Some(false)),
+            data: ProposalCreateData {
+                args: ProposalCreateArgs {
+                    draft,
+                    transaction_index,
+                },
+            }
+            .data(),
+            program_id,
+        };
 
         let message = Message::try_compile(
             &transaction_creator,
@@ -278,42 +348,48 @@ impl InitiateTransfer {
                         args: VaultTransactionCreateArgs {
                             ephemeral_signers: 0,
                             vault_index,
-                            memo,
+                            memo: memo.clone(),
                             transaction_message: transaction_message.try_to_vec().unwrap(),
                         },
                     }
                     .data(),
                     program_id,
                 },
-                Instruction {
-                    accounts: ProposalCreateAccounts {
-                        creator: transaction_creator,
-                        rent_payer: transaction_creator,
-                        proposal: proposal_pda.0,
-                        multisig,
-                        system_program: solana_sdk::system_program::id(),
-                    }
-                    .to_account_metas(Some(false)),
-                    data: ProposalCreateData {
-                        args: ProposalCreateArgs {
-                            draft: false,
-                            transaction_index,
-                        },
-                    }
-                    .data(),
-                    program_id,
-                },
+                proposal_create_instruction.clone(),
             ],
             &[],
             blockhash,
         )
         .unwrap();
 
-        let transaction = VersionedTransaction::try_new(
-            VersionedMessage::V0(message),
-            &[&*transaction_creator_keypair],
-        )
-        .expect("Failed to create transaction");
+        let message = VersionedMessage::V0(message);
+        if exceeds_transaction_size(&message, 1) {
+            progress.finish_and_clear();
+            let signature = BufferedVaultTransaction {
+                multisig,
+                transaction_index,
+                vault_index,
+                ephemeral_signers: 0,
+                memo,
+                transaction_message: transaction_message.try_to_vec().unwrap(),
+                program_id,
+            }
+            .send(
+                rpc_client,
+                &*transaction_creator_keypair,
+                &[proposal_create_instruction],
+                priority_fee_lamports.unwrap_or(200_000),
+            )
+            .await?;
+            println!(
+                "✅ Transaction created successfully. Signature: {}",
+                signature.green()
+            );
+            return Ok(());
+        }
+
+        let transaction = VersionedTransaction::try_new(message, &[&*transaction_creator_keypair])
+            .expect("Failed to create transaction");
         let result = transaction_details(&transaction.clone());
 
         match result {
@@ -398,3 +474,35 @@ impl InitiateTransfer {
         Ok(())
     }
 }
+
+/// The vault transaction message of a transfer from the vault ATA to the recipient ATA,
+/// `verify-proposal` rebuilds it with the same function.
+pub fn transfer_message(
+    vault: &Pubkey,
+    mint_info: &MintInfo,
+    recipient: &Pubkey,
+    amount: u64,
+) -> TransactionMessage {
+    let sender_ata = get_associated_token_address_with_program_id(
+        vault,
+        &mint_info.mint,
+        &mint_info.token_program,
+    );
+    let recipient_ata = get_associated_token_address_with_program_id(
+        recipient,
+        &mint_info.mint,
+        &mint_info.token_program,
+    );
+    TransactionMessage::try_compile(
+        vault,
+        &[transfer_checked_instruction(
+            mint_info,
+            &sender_ata,
+            &recipient_ata,
+            vault,
+            amount,
+        )],
+        &[],
+    )
+    .unwrap()
+}
@@ -0,0 +1,85 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use std::str::FromStr;
+
+use crate::output;
+use crate::squads_decoder::{idl_registry, load_onchain_idls, map_account};
+use crate::utils::print_idl_source;
+
+/// Decodes an account with the IDL of the program owning it, e.g. a protocol config a proposal
+/// is about to change
+#[derive(Args)]
+pub struct InspectAccount {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// The account to decode
+    #[arg(long)]
+    address: String,
+}
+
+impl InspectAccount {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self { rpc_url, address } = self;
+
+        let address = Pubkey::from_str(&address).expect("Invalid account address");
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let account = rpc_client
+            .get_account(&address)
+            .await
+            .map_err(|e| eyre!("Failed to get account {}: {}", address, e))?;
+
+        load_onchain_idls(&rpc_client, &[account.owner]).await;
+        let decoded = map_account(
+            &account.owner,
+            &account.data,
+            &idl_registry(),
+            &chainparser::borsh::BorshDeserializer,
+        )
+        .map_err(|e| eyre!("Failed to decode {}: {}", address, e))?;
+
+        println!("Account -> {}", address);
+        println!("  Owner: {} ({})", account.owner, decoded.program_name);
+        print_idl_source(decoded.idl_source);
+        println!("  Type: {}", decoded.account_name.bright_cyan());
+        println!("  Lamports: {}", account.lamports);
+        println!("  Data: {} bytes", account.data.len());
+        if decoded.trailing_bytes > 0 {
+            println!(
+                "  {}",
+                format!(
+                    "{} bytes after the {} layout were not decoded",
+                    decoded.trailing_bytes, decoded.account_name
+                )
+                .bright_black()
+            );
+        }
+        println!("{}", serde_json::to_string_pretty(&decoded.decoded)?);
+
+        output::set(
+            "account",
+            json!({
+                "address": address.to_string(),
+                "owner": account.owner.to_string(),
+                "program": decoded.program_name,
+                "idl_source": decoded.idl_source.describe(),
+                "type": decoded.account_name,
+                "lamports": account.lamports,
+                "data_len": account.data.len(),
+                "trailing_bytes": decoded.trailing_bytes,
+                "data": decoded.decoded,
+            }),
+        );
+
+        Ok(())
+    }
+}
@@ -0,0 +1,239 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::pda::get_proposal_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::state::{Multisig, Permission, Proposal, ProposalStatus};
+use std::str::FromStr;
+
+use crate::command::display_multisig::fetch_multisig;
+use crate::command::display_spending_limit::fetch_unix_timestamp;
+use crate::output;
+
+/// `getMultipleAccounts` accepts at most 100 keys.
+const ACCOUNTS_PER_REQUEST: usize = 100;
+
+#[derive(Args)]
+pub struct ListProposals {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The multisig to list the proposals of
+    #[arg(long)]
+    multisig_address: String,
+
+    /// First transaction index to list, defaults to 1
+    #[arg(long)]
+    from_index: Option<u64>,
+
+    /// Last transaction index to list, defaults to the latest transaction
+    #[arg(long)]
+    to_index: Option<u64>,
+
+    /// Only list proposals that are: all, active (open for voting), approved (approved but not executed) or stale
+    #[arg(long, default_value = "all")]
+    filter: String,
+}
+
+impl ListProposals {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            multisig_address,
+            from_index,
+            to_index,
+            filter,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let multisig_address =
+            Pubkey::from_str(&multisig_address).expect("Invalid multisig address");
+
+        let filter = filter.to_lowercase();
+        if !matches!(filter.as_str(), "all" | "active" | "approved" | "stale") {
+            return Err(eyre!(
+                "Invalid filter '{}'. Please use one of: all, active, approved, stale",
+                filter
+            ));
+        }
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let multisig = fetch_multisig(&rpc_client, &multisig_address, &program_id).await?;
+        let now = fetch_unix_timestamp(&rpc_client).await?;
+
+        let from_index = from_index.unwrap_or(1).max(1);
+        let to_index = to_index
+            .unwrap_or(multisig.transaction_index)
+            .min(multisig.transaction_index);
+        let indexes: Vec<u64> = (from_index..=to_index).collect();
+
+        println!(
+            "Proposals of {} (#{} to #{}, threshold {}, stale up to #{})",
+            multisig_address,
+            from_index,
+            to_index,
+            multisig.threshold,
+            multisig.stale_transaction_index
+        );
+
+        let mut listed = 0;
+        for indexes in indexes.chunks(ACCOUNTS_PER_REQUEST) {
+            let keys: Vec<Pubkey> = indexes
+                .iter()
+                .map(|&index| get_proposal_pda(&multisig_address, index, Some(&program_id)).0)
+                .collect();
+            let accounts = rpc_client.get_multiple_accounts(&keys).await?;
+
+            for (&index, account) in indexes.iter().zip(accounts) {
+                // Closed, or a transaction that never got a proposal.
+                let Some(account) = account else {
+                    continue;
+                };
+                if account.owner != program_id {
+                    continue;
+                }
+                let Ok(proposal) = Proposal::try_deserialize(&mut account.data.as_slice()) else {
+                    continue;
+                };
+
+                let is_stale = index <= multisig.stale_transaction_index
+                    && matches!(
+                        proposal.status,
+                        ProposalStatus::Draft { .. } | ProposalStatus::Active { .. }
+                    );
+                let keep = match filter.as_str() {
+                    "active" => {
+                        matches!(proposal.status, ProposalStatus::Active { .. }) && !is_stale
+                    }
+                    "approved" => matches!(proposal.status, ProposalStatus::Approved { .. }),
+                    "stale" => is_stale,
+                    _ => true,
+                };
+                if !keep {
+                    continue;
+                }
+
+                print_proposal_summary(index, &proposal, &multisig, is_stale, now);
+                listed += 1;
+            }
+        }
+
+        if listed == 0 {
+            println!("No proposals found.");
+        }
+
+        Ok(())
+    }
+}
+
+fn print_proposal_summary(
+    index: u64,
+    proposal: &Proposal,
+    multisig: &Multisig,
+    is_stale: bool,
+    now: i64,
+) {
+    let voters = Multisig::num_voters(&multisig.members);
+    // Same cutoff the program uses to reject a proposal.
+    let cutoff = voters.saturating_sub(usize::from(multisig.threshold)) + 1;
+
+    #[allow(deprecated)]
+    let (status, timestamp) = match proposal.status {
+        ProposalStatus::Draft { timestamp } => ("Draft".yellow(), Some(timestamp)),
+        ProposalStatus::Active { timestamp } => ("Active".bright_cyan(), Some(timestamp)),
+        ProposalStatus::Approved { timestamp } => ("Approved".bright_green(), Some(timestamp)),
+        ProposalStatus::Rejected { timestamp } => ("Rejected".red(), Some(timestamp)),
+        ProposalStatus::Executed { timestamp } => ("Executed".green(), Some(timestamp)),
+        ProposalStatus::Cancelled { timestamp } => ("Cancelled".bright_black(), Some(timestamp)),
+        ProposalStatus::Executing => ("Executing".yellow(), None),
+        _ => ("Unknown".red(), None),
+    };
+    let since = timestamp
+        .map(|timestamp| {
+            format!(
+                " since {}s ago (unix {})",
+                now.saturating_sub(timestamp),
+                timestamp
+            )
+        })
+        .unwrap_or_default();
+    let missing: Vec<String> = multisig
+        .members
+        .iter()
+        .filter(|m| m.permissions.has(Permission::Vote))
+        .filter(|m| !proposal.approved.contains(&m.key) && !proposal.rejected.contains(&m.key))
+        .map(|m| m.key.to_string())
+        .collect();
+    let keys = |keys: &[Pubkey]| keys.iter().map(|k| k.to_string()).collect::<Vec<_>>();
+    output::push(
+        "proposals",
+        json!({
+            "index": index,
+            "status": status.input,
+            "timestamp": timestamp,
+            "stale": is_stale,
+            "approved": keys(&proposal.approved),
+            "rejected": keys(&proposal.rejected),
+            "cancelled": keys(&proposal.cancelled),
+            "threshold": multisig.threshold,
+            "rejection_cutoff": cutoff,
+            "not_voted": missing,
+        }),
+    );
+
+    let stale = if is_stale {
+        format!(" {}", "[stale]".red())
+    } else {
+        String::new()
+    };
+
+    println!();
+    println!("#{:<6} {}{}{}", index, status, since, stale);
+    println!(
+        "  Approved {}/{}  Rejected {}/{}  Cancelled {}/{}",
+        proposal.approved.len(),
+        multisig.threshold,
+        proposal.rejected.len(),
+        cutoff,
+        proposal.cancelled.len(),
+        multisig.threshold
+    );
+
+    match proposal.status {
+        ProposalStatus::Draft { .. } | ProposalStatus::Active { .. }
+            if !is_stale && !missing.is_empty() =>
+        {
+            println!("  Not voted yet:");
+            for member in missing {
+                println!("    - {}", member);
+            }
+        }
+        ProposalStatus::Approved { timestamp } => {
+            let executable_at = timestamp + i64::from(multisig.time_lock);
+            if executable_at > now {
+                println!(
+                    "  Time lock ends in {}s (unix {})",
+                    executable_at - now,
+                    executable_at
+                );
+            }
+        }
+        _ => {}
+    }
+}
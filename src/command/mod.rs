@@ -1,22 +1,75 @@
+use crate::command::address_book::AddressBook;
+use crate::command::batch_accounts_close::BatchAccountsClose;
+use crate::command::batch_add_transaction::BatchAddTransaction;
+use crate::command::batch_create::BatchCreate;
+use crate::command::batch_execute_transaction::BatchExecuteTransaction;
+use crate::command::config_transaction_create::ConfigTransactionCreate;
+use crate::command::config_transaction_execute::ConfigTransactionExecute;
+use crate::command::display_batch::DisplayBatch;
+use crate::command::display_multisig::DisplayMultisig;
+use crate::command::display_spending_limit::DisplaySpendingLimit;
 use crate::command::display_transaction::DisplayTransaction;
 use crate::command::display_vault::DisplayVault;
+use crate::command::idl::Idl;
 use crate::command::initiate_program_upgrade::InitiateProgramUpgrade;
 use crate::command::initiate_transfer::InitiateTransfer;
+use crate::command::inspect_account::InspectAccount;
+use crate::command::list_proposals::ListProposals;
+use crate::command::multisig_add_member::MultisigAddMember;
+use crate::command::multisig_add_spending_limit::MultisigAddSpendingLimit;
+use crate::command::multisig_change_threshold::MultisigChangeThreshold;
 use crate::command::multisig_create::MultisigCreate;
+use crate::command::multisig_remove_member::MultisigRemoveMember;
+use crate::command::multisig_remove_spending_limit::MultisigRemoveSpendingLimit;
+use crate::command::multisig_set_config_authority::MultisigSetConfigAuthority;
+use crate::command::multisig_set_rent_collector::MultisigSetRentCollector;
+use crate::command::multisig_set_time_lock::MultisigSetTimeLock;
+use crate::command::program_config::ProgramConfig;
 use crate::command::proposal_vote::ProposalVote;
+use crate::command::reclaim_rent::ReclaimRent;
+use crate::command::spending_limit_use::SpendingLimitUse;
+use crate::command::vault_inventory::VaultInventory;
 use crate::command::vault_transaction_accounts_close::VaultTransactionAccountsClose;
 use crate::command::vault_transaction_execute::VaultTransactionExecute;
+use crate::command::verify_proposal::VerifyProposal;
 
 use clap::Subcommand;
 
+pub mod address_book;
+pub mod batch_accounts_close;
+pub mod batch_add_transaction;
+pub mod batch_create;
+pub mod batch_execute_transaction;
+pub mod config_transaction_create;
+pub mod config_transaction_execute;
+pub mod display_batch;
+pub mod display_multisig;
+pub mod display_spending_limit;
 pub mod display_transaction;
 pub mod display_vault;
+pub mod idl;
 pub mod initiate_program_upgrade;
 pub mod initiate_transfer;
+pub mod inspect_account;
+pub mod list_proposals;
+pub mod multisig_add_member;
+pub mod multisig_add_spending_limit;
+pub mod multisig_change_threshold;
+pub mod multisig_config;
 pub mod multisig_create;
+pub mod multisig_remove_member;
+pub mod multisig_remove_spending_limit;
+pub mod multisig_set_config_authority;
+pub mod multisig_set_rent_collector;
+pub mod multisig_set_time_lock;
+pub mod program_config;
 pub mod proposal_vote;
+pub mod reclaim_rent;
+pub mod spending_limit_use;
+pub mod vault_inventory;
 pub mod vault_transaction_accounts_close;
 pub mod vault_transaction_execute;
+pub mod verify_proposal;
 
 #[derive(Subcommand)]
 pub enum Command {
@@ -28,4 +81,30 @@ pub enum Command {
     VaultTransactionAccountsClose(VaultTransactionAccountsClose),
     DisplayTransaction(DisplayTransaction),
     InitiateProgramUpgrade(InitiateProgramUpgrade),
+    ConfigTransactionCreate(ConfigTransactionCreate),
+    ConfigTransactionExecute(ConfigTransactionExecute),
+    DisplaySpendingLimit(DisplaySpendingLimit),
+    MultisigAddSpendingLimit(MultisigAddSpendingLimit),
+    MultisigRemoveSpendingLimit(MultisigRemoveSpendingLimit),
+    SpendingLimitUse(SpendingLimitUse),
+    BatchCreate(BatchCreate),
+    BatchAddTransaction(BatchAddTransaction),
+    BatchExecuteTransaction(BatchExecuteTransaction),
+    DisplayBatch(DisplayBatch),
+    BatchAccountsClose(BatchAccountsClose),
+    ReclaimRent(ReclaimRent),
+    ProgramConfig(ProgramConfig),
+    MultisigAddMember(MultisigAddMember),
+    MultisigRemoveMember(MultisigRemoveMember),
+    MultisigChangeThreshold(MultisigChangeThreshold),
+    MultisigSetTimeLock(MultisigSetTimeLock),
+    MultisigSetConfigAuthority(MultisigSetConfigAuthority),
+    MultisigSetRentCollector(MultisigSetRentCollector),
+    DisplayMultisig(DisplayMultisig),
+    ListProposals(ListProposals),
+    VaultInventory(VaultInventory),
+    AddressBook(AddressBook),
+    VerifyProposal(VerifyProposal),
+    Idl(Idl),
+    InspectAccount(InspectAccount),
 }
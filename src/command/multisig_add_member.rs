@@ -0,0 +1,85 @@
+use clap::Args;
+use eyre::eyre;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigAddMemberArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigAddMember as MultisigAddMemberData;
+use squads_multisig::state::ConfigAction;
+
+use crate::command::config_transaction_execute::apply_config_actions;
+use crate::command::multisig_config::MultisigConfigContext;
+use crate::utils::parse_members;
+
+#[derive(Args)]
+pub struct MultisigAddMember {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Member to add, in the format <public_key>,<permission>
+    #[arg(long)]
+    new_member: String,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigAddMember {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            new_member,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let new_member = parse_members(vec![new_member])
+            .map_err(|e| eyre!(e))?
+            .remove(0);
+
+        let data = MultisigAddMemberData {
+            args: MultisigAddMemberArgs {
+                new_member: new_member.clone(),
+                memo,
+            },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("add a member", data, |multisig| {
+            if multisig.is_member(new_member.key).is_some() {
+                return Err(eyre!(
+                    "{} is already a member of the multisig",
+                    new_member.key
+                ));
+            }
+            apply_config_actions(multisig, &[ConfigAction::AddMember { new_member }])
+        })
+        .await
+    }
+}
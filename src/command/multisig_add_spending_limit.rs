@@ -0,0 +1,264 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::client::get_multisig;
+use squads_multisig::pda::get_spending_limit_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::MultisigAddSpendingLimitArgs;
+use squads_multisig::squads_multisig_program::accounts::MultisigAddSpendingLimit as MultisigAddSpendingLimitAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::MultisigAddSpendingLimit as MultisigAddSpendingLimitData;
+
+use crate::command::display_spending_limit::describe_mint;
+use crate::token_amount::{fetch_mint_info, parse_ui_amount};
+use crate::utils::{
+    create_signer_from_path, ensure_config_authority, parse_period, send_and_confirm_transaction,
+    transaction_details,
+};
+
+#[derive(Args)]
+pub struct MultisigAddSpendingLimit {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to add the spending limit to
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Mint of the spending limit, omit for SOL
+    #[arg(long)]
+    mint: Option<String>,
+
+    /// Vault index the spending limit applies to
+    #[arg(long, default_value_t = 0)]
+    vault_index: u8,
+
+    /// Amount of the spending limit in base units of the mint
+    #[arg(long, conflicts_with = "amount", required_unless_present = "amount")]
+    token_amount_u64: Option<u64>,
+
+    /// Amount of the spending limit in tokens, e.g. 12.5, converted with the decimals of the mint
+    #[arg(long)]
+    amount: Option<String>,
+
+    /// Reset period of the spending limit: OneTime, Day, Week or Month
+    #[arg(long, default_value = "OneTime")]
+    period: String,
+
+    /// Members allowed to use the spending limit
+    #[arg(long, value_delimiter = ' ')]
+    members: Vec<String>,
+
+    /// Allowed destinations, any destination if empty
+    #[arg(long, value_delimiter = ' ')]
+    destinations: Vec<String>,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigAddSpendingLimit {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            mint,
+            vault_index,
+            token_amount_u64,
+            amount,
+            period,
+            members,
+            destinations,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let config_authority_keypair = create_signer_from_path(keypair).unwrap();
+
+        let config_authority = config_authority_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        ensure_config_authority(&multisig_data, &config_authority)?;
+
+        // The SOL spending limit is expressed with the default pubkey as mint.
+        let mint = mint
+            .map(|m| Pubkey::from_str(&m).expect("Invalid mint address"))
+            .unwrap_or_default();
+        let mint_info = fetch_mint_info(&rpc_client, &mint).await?;
+        let amount = match (token_amount_u64, amount) {
+            (Some(token_amount_u64), _) => token_amount_u64,
+            (None, Some(amount)) => parse_ui_amount(&amount, mint_info.decimals)?,
+            (None, None) => return Err(eyre!("Either --amount or --token-amount-u64 is required")),
+        };
+        let period = parse_period(&period).map_err(|e| eyre!(e))?;
+
+        if members.is_empty() {
+            return Err(eyre!("At least one spending limit member is required"));
+        }
+        let mut members = members
+            .iter()
+            .map(|m| Pubkey::from_str(m).expect("Invalid member address"))
+            .collect::<Vec<_>>();
+        members.sort();
+        members.dedup();
+        for member in &members {
+            if !multisig_data.members.iter().any(|m| m.key == *member) {
+                return Err(eyre!("{} is not a member of the multisig", member));
+            }
+        }
+        let destinations = destinations
+            .iter()
+            .map(|d| Pubkey::from_str(d).expect("Invalid destination address"))
+            .collect::<Vec<_>>();
+
+        let create_key = Keypair::new().pubkey();
+        let spending_limit_pda = get_spending_limit_pda(&multisig, &create_key, Some(&program_id));
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to add a spending limit, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Config Authority:  {}", config_authority);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Spending Limit:     {}", spending_limit_pda.0);
+        println!("Vault Index:        {}", vault_index);
+        println!("Mint:               {}", describe_mint(&mint_info));
+        println!("Amount:             {}", mint_info.display(amount));
+        println!("Period:             {:?}", period);
+        println!("Members:");
+        for member in &members {
+            println!("  - {}", member);
+        }
+        if destinations.is_empty() {
+            println!(
+                "{}",
+                "⚠️ WARNING: No destinations given, members can send to any address.".red()
+            );
+        } else {
+            println!("Destinations:");
+            for destination in &destinations {
+                println!("  - {}", destination);
+            }
+        }
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &config_authority,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: MultisigAddSpendingLimitAccounts {
+                        multisig,
+                        config_authority,
+                        spending_limit: spending_limit_pda.0,
+                        rent_payer: config_authority,
+                        system_program: system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: MultisigAddSpendingLimitData {
+                        args: MultisigAddSpendingLimitArgs {
+                            create_key,
+                            vault_index,
+                            mint,
+                            amount,
+                            period,
+                            members,
+                            destinations,
+                            memo,
+                        },
+                    }
+                    .data(),
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*config_authority_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Added Spending Limit: {}. Signature: {}",
+            spending_limit_pda.0,
+            signature.green()
+        );
+        Ok(())
+    }
+}
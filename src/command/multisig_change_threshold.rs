@@ -0,0 +1,73 @@
+use clap::Args;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigChangeThresholdArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigChangeThreshold as MultisigChangeThresholdData;
+use squads_multisig::state::ConfigAction;
+
+use crate::command::config_transaction_execute::apply_config_actions;
+use crate::command::multisig_config::MultisigConfigContext;
+
+#[derive(Args)]
+pub struct MultisigChangeThreshold {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The new approval threshold
+    #[arg(long)]
+    new_threshold: u16,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigChangeThreshold {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            new_threshold,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let data = MultisigChangeThresholdData {
+            args: MultisigChangeThresholdArgs {
+                new_threshold,
+                memo,
+            },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("change the threshold", data, |multisig| {
+            apply_config_actions(multisig, &[ConfigAction::ChangeThreshold { new_threshold }])
+        })
+        .await
+    }
+}
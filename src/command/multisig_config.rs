@@ -0,0 +1,171 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use colored::Colorize;
+use dialoguer::Confirm;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::client::get_multisig;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::accounts::MultisigConfig as MultisigConfigAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::state::Multisig;
+
+use crate::command::config_transaction_execute::print_multisig_change;
+use crate::utils::{
+    create_signer_from_path, ensure_config_authority, send_and_confirm_transaction,
+    transaction_details,
+};
+
+/// Arguments shared by every command signed directly by the config authority of a controlled multisig.
+pub struct MultisigConfigContext {
+    pub rpc_url: Option<String>,
+    pub program_id: Option<String>,
+    pub keypair: String,
+    pub multisig_pubkey: String,
+    pub priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigConfigContext {
+    /// Reviews the change as a before/after view of the multisig, then sends the `MultisigConfig`
+    /// instruction built from `data`. `apply` predicts the multisig state after the instruction.
+    pub async fn send(
+        self,
+        description: &str,
+        data: Vec<u8>,
+        apply: impl FnOnce(&Multisig) -> eyre::Result<Multisig>,
+    ) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let config_authority_keypair = create_signer_from_path(keypair).unwrap();
+
+        let config_authority = config_authority_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        ensure_config_authority(&multisig_data, &config_authority)?;
+        let multisig_after = apply(&multisig_data)?;
+
+        println!();
+        println!(
+            "{}",
+            format!(
+                "👀 You're about to {} of a controlled multisig, please review the details:",
+                description
+            )
+            .yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Config Authority:  {}", config_authority);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        if multisig_after.config_authority != multisig_data.config_authority {
+            println!(
+                "Config Authority:   {} -> {}",
+                multisig_data.config_authority,
+                multisig_after.config_authority.to_string().bright_cyan()
+            );
+            if multisig_after.config_authority == Pubkey::default() {
+                println!(
+                    "{}",
+                    "⚠️ WARNING: The multisig becomes autonomous, further changes need config transactions."
+                        .red()
+                );
+            } else {
+                println!(
+                    "{}",
+                    "⚠️ WARNING: You lose control over the multisig configuration to the new authority."
+                        .red()
+                );
+            }
+        }
+        print_multisig_change(&multisig_data, &multisig_after);
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &config_authority,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    // The config authority pays for the reallocation when the multisig grows.
+                    accounts: MultisigConfigAccounts {
+                        multisig,
+                        config_authority,
+                        rent_payer: Some(config_authority),
+                        system_program: Some(system_program::id()),
+                    }
+                    .to_account_metas(Some(false)),
+                    data,
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*config_authority_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Updated multisig {}. Signature: {}",
+            multisig,
+            signature.green()
+        );
+        Ok(())
+    }
+}
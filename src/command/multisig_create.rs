@@ -24,9 +24,10 @@ use squads_multisig::squads_multisig_program::accounts::MultisigCreateV2 as Mult
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
 use squads_multisig::squads_multisig_program::instruction::MultisigCreateV2 as MultisigCreateV2Data;
 use squads_multisig::squads_multisig_program::state::ProgramConfig;
-use squads_multisig::state::{Member, Permissions};
 
-use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
+use crate::utils::{
+    create_signer_from_path, parse_members, send_and_confirm_transaction, transaction_details,
+};
 #[derive(Args)]
 pub struct MultisigCreate {
     /// RPC URL
@@ -248,27 +249,3 @@ impl MultisigCreate {
         Ok(())
     }
 }
-fn parse_members(member_strings: Vec<String>) -> Result<Vec<Member>, String> {
-    member_strings
-        .into_iter()
-        .map(|s| {
-            let parts: Vec<&str> = s.split(',').collect();
-            if parts.len() != 2 {
-                return Err(
-                    "Each entry must be in the format <public_key>,<permission>".to_string()
-                );
-            }
-
-            let key =
-                Pubkey::from_str(parts[0]).map_err(|_| "Invalid public key format".to_string())?;
-            let permissions = parts[1]
-                .parse::<u8>()
-                .map_err(|_| "Invalid permission format".to_string())?;
-
-            Ok(Member {
-                key,
-                permissions: Permissions { mask: permissions },
-            })
-        })
-        .collect()
-}
@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigRemoveMemberArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigRemoveMember as MultisigRemoveMemberData;
+use squads_multisig::state::ConfigAction;
+
+use crate::command::config_transaction_execute::apply_config_actions;
+use crate::command::multisig_config::MultisigConfigContext;
+
+#[derive(Args)]
+pub struct MultisigRemoveMember {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// Member to remove
+    #[arg(long)]
+    old_member: String,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigRemoveMember {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            old_member,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let old_member = Pubkey::from_str(&old_member).expect("Invalid member address");
+
+        let data = MultisigRemoveMemberData {
+            args: MultisigRemoveMemberArgs { old_member, memo },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("remove a member", data, |multisig| {
+            apply_config_actions(multisig, &[ConfigAction::RemoveMember { old_member }])
+        })
+        .await
+    }
+}
@@ -0,0 +1,193 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::client::get_multisig;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::MultisigRemoveSpendingLimitArgs;
+use squads_multisig::squads_multisig_program::accounts::MultisigRemoveSpendingLimit as MultisigRemoveSpendingLimitAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::MultisigRemoveSpendingLimit as MultisigRemoveSpendingLimitData;
+
+use crate::command::display_spending_limit::{
+    fetch_spending_limit, fetch_unix_timestamp, print_spending_limit,
+};
+use crate::token_amount::fetch_mint_info;
+use crate::utils::{
+    create_signer_from_path, ensure_config_authority, send_and_confirm_transaction,
+    transaction_details,
+};
+
+#[derive(Args)]
+pub struct MultisigRemoveSpendingLimit {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig owning the spending limit
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The spending limit account to remove
+    #[arg(long)]
+    spending_limit: String,
+
+    /// Receiver of the reclaimed rent, defaults to the config authority
+    #[arg(long)]
+    rent_collector: Option<String>,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigRemoveSpendingLimit {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            spending_limit,
+            rent_collector,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let config_authority_keypair = create_signer_from_path(keypair).unwrap();
+
+        let config_authority = config_authority_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+        let spending_limit_key =
+            Pubkey::from_str(&spending_limit).expect("Invalid spending limit address");
+        let rent_collector = rent_collector
+            .map(|r| Pubkey::from_str(&r).expect("Invalid rent collector address"))
+            .unwrap_or(config_authority);
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        ensure_config_authority(&multisig_data, &config_authority)?;
+
+        let spending_limit_data =
+            fetch_spending_limit(&rpc_client, &spending_limit_key, &program_id).await?;
+        if spending_limit_data.multisig != multisig {
+            return Err(eyre!(
+                "Spending limit {} belongs to multisig {}",
+                spending_limit_key,
+                spending_limit_data.multisig
+            ));
+        }
+        let now = fetch_unix_timestamp(&rpc_client).await?;
+        let mint_info = fetch_mint_info(&rpc_client, &spending_limit_data.mint).await?;
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to remove a spending limit, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Config Authority:  {}", config_authority);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Spending Limit:     {}", spending_limit_key);
+        print_spending_limit(&spending_limit_data, &mint_info, now);
+        println!("Rent Collector:     {}", rent_collector);
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &config_authority,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: MultisigRemoveSpendingLimitAccounts {
+                        multisig,
+                        config_authority,
+                        spending_limit: spending_limit_key,
+                        rent_collector,
+                    }
+                    .to_account_metas(Some(false)),
+                    data: MultisigRemoveSpendingLimitData {
+                        args: MultisigRemoveSpendingLimitArgs { memo },
+                    }
+                    .data(),
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction = VersionedTransaction::try_new(
+            VersionedMessage::V0(message),
+            &[&*config_authority_keypair],
+        )
+        .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Removed Spending Limit: {}. Signature: {}",
+            spending_limit_key,
+            signature.green()
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigSetConfigAuthorityArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigSetConfigAuthority as MultisigSetConfigAuthorityData;
+
+use crate::command::multisig_config::MultisigConfigContext;
+
+#[derive(Args)]
+pub struct MultisigSetConfigAuthority {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The new config authority, the default pubkey makes the multisig autonomous
+    #[arg(long)]
+    config_authority: String,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigSetConfigAuthority {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            config_authority,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let config_authority =
+            Pubkey::from_str(&config_authority).expect("Invalid config authority address");
+
+        let data = MultisigSetConfigAuthorityData {
+            args: MultisigSetConfigAuthorityArgs {
+                config_authority,
+                memo,
+            },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("set the config authority", data, |multisig| {
+            let mut multisig = multisig.clone();
+            multisig.config_authority = config_authority;
+            Ok(multisig)
+        })
+        .await
+    }
+}
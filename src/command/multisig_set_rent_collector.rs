@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use clap::Args;
+use solana_sdk::pubkey::Pubkey;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigSetRentCollectorArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigSetRentCollector as MultisigSetRentCollectorData;
+use squads_multisig::state::ConfigAction;
+
+use crate::command::config_transaction_execute::apply_config_actions;
+use crate::command::multisig_config::MultisigConfigContext;
+
+#[derive(Args)]
+pub struct MultisigSetRentCollector {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The new rent collector, omit to disable rent reclaiming
+    #[arg(long)]
+    rent_collector: Option<String>,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigSetRentCollector {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            rent_collector,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let rent_collector =
+            rent_collector.map(|r| Pubkey::from_str(&r).expect("Invalid rent collector address"));
+
+        let data = MultisigSetRentCollectorData {
+            args: MultisigSetRentCollectorArgs {
+                rent_collector,
+                memo,
+            },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("set the rent collector", data, |multisig| {
+            apply_config_actions(
+                multisig,
+                &[ConfigAction::SetRentCollector {
+                    new_rent_collector: rent_collector,
+                }],
+            )
+        })
+        .await
+    }
+}
@@ -0,0 +1,75 @@
+use clap::Args;
+
+use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::squads_multisig_program::MultisigSetTimeLockArgs;
+use squads_multisig::squads_multisig_program::instruction::MultisigSetTimeLock as MultisigSetTimeLockData;
+use squads_multisig::state::ConfigAction;
+
+use crate::command::config_transaction_execute::apply_config_actions;
+use crate::command::multisig_config::MultisigConfigContext;
+
+#[derive(Args)]
+pub struct MultisigSetTimeLock {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The controlled multisig to update
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The new time lock in seconds
+    #[arg(long)]
+    time_lock: u32,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl MultisigSetTimeLock {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            time_lock,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let data = MultisigSetTimeLockData {
+            args: MultisigSetTimeLockArgs { time_lock, memo },
+        }
+        .data();
+
+        MultisigConfigContext {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        }
+        .send("set the time lock", data, |multisig| {
+            apply_config_actions(
+                multisig,
+                &[ConfigAction::SetTimeLock {
+                    new_time_lock: time_lock,
+                }],
+            )
+        })
+        .await
+    }
+}
@@ -0,0 +1,558 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use serde_json::json;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::{AccountDeserialize, InstructionData};
+use squads_multisig::pda::get_program_config_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::accounts::ProgramConfig as ProgramConfigAccounts;
+use squads_multisig::squads_multisig_program::accounts::ProgramConfigInit as ProgramConfigInitAccounts;
+use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::ProgramConfigInit as ProgramConfigInitData;
+use squads_multisig::squads_multisig_program::instruction::ProgramConfigSetAuthority as ProgramConfigSetAuthorityData;
+use squads_multisig::squads_multisig_program::instruction::ProgramConfigSetMultisigCreationFee as ProgramConfigSetMultisigCreationFeeData;
+use squads_multisig::squads_multisig_program::instruction::ProgramConfigSetTreasury as ProgramConfigSetTreasuryData;
+use squads_multisig::squads_multisig_program::state::ProgramConfig as ProgramConfigAccount;
+use squads_multisig::squads_multisig_program::{
+    ProgramConfigInitArgs, ProgramConfigSetAuthorityArgs, ProgramConfigSetMultisigCreationFeeArgs,
+    ProgramConfigSetTreasuryArgs,
+};
+
+use crate::output;
+use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
+
+/// Administration of the global `ProgramConfig` of a (self-hosted) Squads deployment
+#[derive(Args)]
+pub struct ProgramConfig {
+    #[command(subcommand)]
+    command: ProgramConfigCommand,
+}
+
+#[derive(Subcommand)]
+pub enum ProgramConfigCommand {
+    Init(ProgramConfigInit),
+    Display(ProgramConfigDisplay),
+    SetAuthority(ProgramConfigSetAuthority),
+    SetMultisigCreationFee(ProgramConfigSetMultisigCreationFee),
+    SetTreasury(ProgramConfigSetTreasury),
+}
+
+impl ProgramConfig {
+    pub async fn execute(self) -> eyre::Result<()> {
+        match self.command {
+            ProgramConfigCommand::Init(command) => command.execute().await,
+            ProgramConfigCommand::Display(command) => command.execute().await,
+            ProgramConfigCommand::SetAuthority(command) => command.execute().await,
+            ProgramConfigCommand::SetMultisigCreationFee(command) => command.execute().await,
+            ProgramConfigCommand::SetTreasury(command) => command.execute().await,
+        }
+    }
+}
+
+#[derive(Args)]
+pub struct ProgramConfigInit {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Program Config Initializer Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The authority allowed to update the program config
+    #[arg(long)]
+    authority: String,
+
+    /// Lamports charged for creating a multisig
+    #[arg(long, default_value_t = 0)]
+    multisig_creation_fee: u64,
+
+    /// The account receiving the multisig creation fees
+    #[arg(long)]
+    treasury: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl ProgramConfigInit {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            authority,
+            multisig_creation_fee,
+            treasury,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let initializer_keypair = create_signer_from_path(keypair).unwrap();
+
+        let initializer = initializer_keypair.pubkey();
+
+        let authority = Pubkey::from_str(&authority).expect("Invalid authority address");
+        let treasury = Pubkey::from_str(&treasury).expect("Invalid treasury address");
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let program_config_pda = get_program_config_pda(Some(&program_id));
+        if rpc_client.get_account(&program_config_pda.0).await.is_ok() {
+            return Err(eyre!(
+                "The program config {} is already initialized",
+                program_config_pda.0
+            ));
+        }
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to initialize ProgramConfig, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Initializer:       {}", initializer);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Program Config:          {}", program_config_pda.0);
+        println!("Authority:          {}", authority);
+        println!(
+            "Multisig Creation Fee:          {} lamports ({} SOL)",
+            multisig_creation_fee,
+            lamports_to_sol(multisig_creation_fee)
+        );
+        println!("Treasury:          {}", treasury);
+        println!(
+            "{}",
+            "ℹ️ Only the initializer compiled into the program can sign this instruction."
+                .bright_black()
+        );
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(
+            &initializer,
+            &[
+                ComputeBudgetInstruction::set_compute_unit_price(
+                    priority_fee_lamports.unwrap_or(5000),
+                ),
+                Instruction {
+                    accounts: ProgramConfigInitAccounts {
+                        program_config: program_config_pda.0,
+                        initializer,
+                        system_program: system_program::id(),
+                    }
+                    .to_account_metas(Some(false)),
+                    data: ProgramConfigInitData {
+                        args: ProgramConfigInitArgs {
+                            authority,
+                            multisig_creation_fee,
+                            treasury,
+                        },
+                    }
+                    .data(),
+                    program_id,
+                },
+            ],
+            &[],
+            blockhash,
+        )
+        .unwrap();
+
+        let transaction =
+            VersionedTransaction::try_new(VersionedMessage::V0(message), &[&*initializer_keypair])
+                .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!(
+            "✅ Initialized ProgramConfig: {}. Signature: {}",
+            program_config_pda.0,
+            signature.green()
+        );
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct ProgramConfigDisplay {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+}
+
+impl ProgramConfigDisplay {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let (program_config_key, program_config) =
+            fetch_program_config(&rpc_client, &program_id).await?;
+
+        println!("Program Config -> {}", program_config_key);
+        print_program_config(&program_config);
+        output::set(
+            "program_config",
+            json!({
+                "address": program_config_key.to_string(),
+                "authority": program_config.authority.to_string(),
+                "multisig_creation_fee": program_config.multisig_creation_fee.to_string(),
+                "treasury": program_config.treasury.to_string(),
+            }),
+        );
+
+        Ok(())
+    }
+}
+
+#[derive(Args)]
+pub struct ProgramConfigSetAuthority {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Program Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The new program config authority
+    #[arg(long)]
+    new_authority: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl ProgramConfigSetAuthority {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let new_authority =
+            Pubkey::from_str(&self.new_authority).expect("Invalid new authority address");
+        update_program_config(
+            self.rpc_url,
+            self.program_id,
+            self.keypair,
+            self.priority_fee_lamports,
+            ProgramConfigUpdate::Authority(new_authority),
+        )
+        .await
+    }
+}
+
+#[derive(Args)]
+pub struct ProgramConfigSetMultisigCreationFee {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Program Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The new multisig creation fee in lamports
+    #[arg(long)]
+    new_multisig_creation_fee: u64,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl ProgramConfigSetMultisigCreationFee {
+    pub async fn execute(self) -> eyre::Result<()> {
+        update_program_config(
+            self.rpc_url,
+            self.program_id,
+            self.keypair,
+            self.priority_fee_lamports,
+            ProgramConfigUpdate::MultisigCreationFee(self.new_multisig_creation_fee),
+        )
+        .await
+    }
+}
+
+#[derive(Args)]
+pub struct ProgramConfigSetTreasury {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Program Config Authority Keypair
+    #[arg(long)]
+    keypair: String,
+
+    /// The new treasury receiving the multisig creation fees
+    #[arg(long)]
+    new_treasury: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl ProgramConfigSetTreasury {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let new_treasury =
+            Pubkey::from_str(&self.new_treasury).expect("Invalid new treasury address");
+        update_program_config(
+            self.rpc_url,
+            self.program_id,
+            self.keypair,
+            self.priority_fee_lamports,
+            ProgramConfigUpdate::Treasury(new_treasury),
+        )
+        .await
+    }
+}
+
+enum ProgramConfigUpdate {
+    Authority(Pubkey),
+    MultisigCreationFee(u64),
+    Treasury(Pubkey),
+}
+
+/// Shared review-and-confirm flow of the program config setters.
+async fn update_program_config(
+    rpc_url: Option<String>,
+    program_id: Option<String>,
+    keypair: String,
+    priority_fee_lamports: Option<u64>,
+    update: ProgramConfigUpdate,
+) -> eyre::Result<()> {
+    let program_id =
+        program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+    let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+    let authority_keypair = create_signer_from_path(keypair).unwrap();
+
+    let authority = authority_keypair.pubkey();
+
+    let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+    let rpc_url_clone = rpc_url.clone();
+    let rpc_client = RpcClient::new(rpc_url);
+
+    let (program_config_key, program_config) =
+        fetch_program_config(&rpc_client, &program_id).await?;
+    if program_config.authority != authority {
+        return Err(eyre!(
+            "{} is not the program config authority, the authority is {}",
+            authority,
+            program_config.authority
+        ));
+    }
+
+    let (field, before, after, data) = match update {
+        ProgramConfigUpdate::Authority(new_authority) => (
+            "Authority",
+            program_config.authority.to_string(),
+            new_authority.to_string(),
+            ProgramConfigSetAuthorityData {
+                args: ProgramConfigSetAuthorityArgs { new_authority },
+            }
+            .data(),
+        ),
+        ProgramConfigUpdate::MultisigCreationFee(new_multisig_creation_fee) => (
+            "Multisig Creation Fee",
+            format!("{} lamports", program_config.multisig_creation_fee),
+            format!("{} lamports", new_multisig_creation_fee),
+            ProgramConfigSetMultisigCreationFeeData {
+                args: ProgramConfigSetMultisigCreationFeeArgs {
+                    new_multisig_creation_fee,
+                },
+            }
+            .data(),
+        ),
+        ProgramConfigUpdate::Treasury(new_treasury) => (
+            "Treasury",
+            program_config.treasury.to_string(),
+            new_treasury.to_string(),
+            ProgramConfigSetTreasuryData {
+                args: ProgramConfigSetTreasuryArgs { new_treasury },
+            }
+            .data(),
+        ),
+    };
+
+    println!();
+    println!(
+        "{}",
+        "👀 You're about to update ProgramConfig, please review the details:".yellow()
+    );
+    println!();
+    println!("RPC Cluster URL:   {}", rpc_url_clone);
+    println!("Program ID:        {}", program_id);
+    println!("Authority:       {}", authority);
+    println!();
+    println!("⚙️ Config Parameters");
+    println!("Program Config:          {}", program_config_key);
+    println!("{}:          {} → {}", field, before, after.bright_cyan());
+    if field == "Authority" {
+        println!(
+            "{}",
+            "⚠️ WARNING: You lose control over the program config once the authority changes."
+                .red()
+        );
+    }
+    println!();
+
+    let proceed = Confirm::new()
+        .with_prompt("Do you want to proceed?")
+        .default(false)
+        .interact()?;
+    if !proceed {
+        println!("OK, aborting.");
+        return Ok(());
+    }
+    println!();
+
+    let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+    progress.enable_steady_tick(Duration::from_millis(100));
+
+    let blockhash = rpc_client
+        .get_latest_blockhash()
+        .await
+        .expect("Failed to get blockhash");
+
+    let message = Message::try_compile(
+        &authority,
+        &[
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee_lamports.unwrap_or(5000)),
+            Instruction {
+                accounts: ProgramConfigAccounts {
+                    program_config: program_config_key,
+                    authority,
+                }
+                .to_account_metas(Some(false)),
+                data,
+                program_id,
+            },
+        ],
+        &[],
+        blockhash,
+    )
+    .unwrap();
+
+    let transaction =
+        VersionedTransaction::try_new(VersionedMessage::V0(message), &[&*authority_keypair])
+            .expect("Failed to create transaction");
+    if let Err(e) = transaction_details(&transaction) {
+        eprintln!("❌ Error processing transaction details: {}", e);
+    }
+
+    let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+    println!(
+        "✅ Updated ProgramConfig {}. Signature: {}",
+        field,
+        signature.green()
+    );
+    Ok(())
+}
+
+pub async fn fetch_program_config(
+    rpc_client: &RpcClient,
+    program_id: &Pubkey,
+) -> eyre::Result<(Pubkey, ProgramConfigAccount)> {
+    let program_config_pda = get_program_config_pda(Some(program_id));
+    let account = rpc_client
+        .get_account(&program_config_pda.0)
+        .await
+        .map_err(|e| {
+            eyre!(
+                "Failed to get program config {}, is it initialized? {}",
+                program_config_pda.0,
+                e
+            )
+        })?;
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+            program_config_pda.0,
+            account.owner
+        ));
+    }
+    let program_config = ProgramConfigAccount::try_deserialize(&mut account.data.as_slice())
+        .map_err(|_| eyre!("{} is not a program config account", program_config_pda.0))?;
+    Ok((program_config_pda.0, program_config))
+}
+
+pub fn print_program_config(program_config: &ProgramConfigAccount) {
+    println!("Authority:              {}", program_config.authority);
+    println!(
+        "Multisig Creation Fee:  {} lamports ({} SOL)",
+        program_config.multisig_creation_fee,
+        lamports_to_sol(program_config.multisig_creation_fee)
+    );
+    println!("Treasury:               {}", program_config.treasury);
+}
@@ -4,25 +4,33 @@ use std::time::Duration;
 use clap::Args;
 use colored::Colorize;
 use dialoguer::Confirm;
+use eyre::eyre;
 use indicatif::ProgressBar;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::message::VersionedMessage;
 use solana_sdk::message::v0::Message;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::transaction::VersionedTransaction;
 
-use squads_multisig::anchor_lang::InstructionData;
+use squads_multisig::anchor_lang::{AccountDeserialize, AnchorSerialize, InstructionData};
+use squads_multisig::client::get_multisig;
 use squads_multisig::pda::get_proposal_pda;
 use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::squads_multisig_program::ProposalVoteArgs;
 use squads_multisig::squads_multisig_program::accounts::ProposalVote as ProposalVoteAccounts;
 use squads_multisig::squads_multisig_program::anchor_lang::ToAccountMetas;
+use squads_multisig::squads_multisig_program::instruction::ProposalActivate;
 use squads_multisig::squads_multisig_program::instruction::ProposalApprove;
 use squads_multisig::squads_multisig_program::instruction::ProposalCancel;
 use squads_multisig::squads_multisig_program::instruction::ProposalReject;
+use squads_multisig::state::{Proposal, ProposalStatus};
 
-use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
+use crate::fingerprint::{fetch_transaction_fingerprint, format_fingerprint};
+use crate::utils::{
+    anchor_discriminator, create_signer_from_path, send_and_confirm_transaction,
+    transaction_details,
+};
 
 #[derive(Args)]
 pub struct ProposalVote {
@@ -46,7 +54,7 @@ pub struct ProposalVote {
     #[arg(long)]
     multisig_pubkey: String,
 
-    /// Vote action to cast
+    /// Vote action to cast: approve, reject, cancel or activate (opens voting on a draft)
     #[arg(long)]
     action: String,
 
@@ -85,6 +93,36 @@ impl ProposalVote {
         let proposal_pda = get_proposal_pda(&multisig, transaction_index, Some(&program_id));
 
         let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let action = action.to_lowercase();
+        if !matches!(
+            action.as_str(),
+            "approve" | "ap" | "reject" | "rj" | "cancel" | "cl" | "activate" | "ac"
+        ) {
+            eprintln!(
+                "Invalid action. Please use one of: Approve, Reject, Cancel, Activate (or their short forms)"
+            );
+            std::process::exit(1);
+        }
+        let is_cancel = matches!(action.as_str(), "cancel" | "cl");
+
+        let proposal_account = rpc_client
+            .get_account(&proposal_pda.0)
+            .await
+            .map_err(|e| eyre!("Failed to get proposal account: {}", e))?;
+        let proposal = Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a proposal account", proposal_pda.0))?;
+
+        // The legacy cancel only works while the cancelled list still fits the account, which
+        // was sized for the members at proposal creation. V2 reallocates, paid by the member.
+        let cancel_size = Proposal::size(0)
+            + 32 * (proposal.approved.len()
+                + proposal.rejected.len()
+                + proposal.cancelled.len()
+                + 1);
+        let use_cancel_v2 = is_cancel && cancel_size > proposal_account.data.len();
 
         println!();
         println!(
@@ -92,7 +130,7 @@ impl ProposalVote {
             "👀 You're about to vote on a proposal, please review the details:".yellow()
         );
         println!();
-        println!("RPC Cluster URL:   {}", rpc_url);
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
         println!("Program ID:        {}", program_id);
         println!("Your Public Key:       {}", transaction_creator);
         println!();
@@ -100,6 +138,62 @@ impl ProposalVote {
         println!("Multisig Key:       {}", multisig_pubkey);
         println!("Transaction Index:       {}", transaction_index);
         println!("Vote Type:       {}", action);
+        println!("Proposal Status:       {:?}", proposal.status);
+        if matches!(action.as_str(), "approve" | "ap") {
+            match fetch_transaction_fingerprint(
+                &rpc_client,
+                &multisig,
+                transaction_index,
+                &program_id,
+            )
+            .await?
+            {
+                Some(fingerprint) => {
+                    println!(
+                        "Fingerprint:       {}",
+                        format_fingerprint(&fingerprint).bright_green().bold()
+                    );
+                    println!(
+                        "{}",
+                        "ℹ️ Compare the fingerprint with the other signers over a separate channel before approving."
+                            .bright_black()
+                    );
+                }
+                None => println!(
+                    "Fingerprint:       {}",
+                    "not available for batches, review every step with display-transaction"
+                        .yellow()
+                ),
+            }
+        }
+        if is_cancel {
+            let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+            println!(
+                "Cancellations:       {} of {} (threshold)",
+                proposal.cancelled.len(),
+                multisig_data.threshold
+            );
+            println!(
+                "Instruction:       {}",
+                if use_cancel_v2 {
+                    "proposalCancelV2 (reallocates the proposal, you pay the extra rent)"
+                } else {
+                    "proposalCancel"
+                }
+            );
+            println!();
+            println!(
+                "{}",
+                "ℹ️ Cancelling withdraws an Approved proposal before it is executed. Once the cancellations reach the threshold it can never be executed. To oppose an Active proposal, reject it instead."
+                    .bright_black()
+            );
+            if !matches!(proposal.status, ProposalStatus::Approved { .. }) {
+                return Err(eyre!(
+                    "Only Approved proposals can be cancelled, this one is {:?}",
+                    proposal.status
+                ));
+            }
+        }
         println!();
 
         let proceed = Confirm::new()
@@ -112,8 +206,6 @@ impl ProposalVote {
         }
         println!();
 
-        let rpc_client = RpcClient::new(rpc_url);
-
         let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
         progress.enable_steady_tick(Duration::from_millis(100));
 
@@ -122,7 +214,14 @@ impl ProposalVote {
             .await
             .expect("Failed to get blockhash");
 
-        let data = match action.to_lowercase().as_str() {
+        let mut accounts = ProposalVoteAccounts {
+            member: transaction_creator,
+            multisig,
+            proposal: proposal_pda.0,
+        }
+        .to_account_metas(Some(false));
+
+        let data = match action.as_str() {
             "approve" | "ap" => ProposalApprove {
                 args: ProposalVoteArgs { memo },
             }
@@ -131,16 +230,22 @@ impl ProposalVote {
                 args: ProposalVoteArgs { memo },
             }
             .data(),
+            "cancel" | "cl" if use_cancel_v2 => {
+                // Not exposed by `squads-multisig` yet, built from `idl.json`.
+                accounts.push(AccountMeta::new_readonly(
+                    solana_sdk::system_program::id(),
+                    false,
+                ));
+                let mut data = anchor_discriminator("global", "proposal_cancel_v2").to_vec();
+                data.extend(ProposalVoteArgs { memo }.try_to_vec().unwrap());
+                data
+            }
             "cancel" | "cl" => ProposalCancel {
                 args: ProposalVoteArgs { memo },
             }
             .data(),
-            _ => {
-                eprintln!(
-                    "Invalid action. Please use one of: Approve, Reject, Cancel, Activate (or their short forms)"
-                );
-                std::process::exit(1);
-            }
+            // `proposalActivate` takes the same accounts as the votes but no memo.
+            _ => ProposalActivate {}.data(),
         };
 
         let message = Message::try_compile(
@@ -150,12 +255,7 @@ impl ProposalVote {
                     priority_fee_lamports.unwrap_or(5000),
                 ),
                 Instruction {
-                    accounts: ProposalVoteAccounts {
-                        member: transaction_creator,
-                        multisig,
-                        proposal: proposal_pda.0,
-                    }
-                    .to_account_metas(Some(false)),
+                    accounts,
                     data,
                     program_id,
                 },
@@ -0,0 +1,383 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+
+use squads_multisig::anchor_lang::AccountDeserialize;
+use squads_multisig::client::{
+    ConfigTransactionAccountsCloseAccounts, VaultTransactionAccountsCloseAccounts,
+    config_transaction_accounts_close, get_multisig, vault_transaction_accounts_close,
+};
+use squads_multisig::pda::{get_proposal_pda, get_transaction_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::state::VaultTransaction;
+use squads_multisig::state::{Batch, ConfigTransaction, Proposal, ProposalStatus};
+
+use crate::command::batch_accounts_close::batch_close_instructions;
+use crate::transaction_buffer::exceeds_transaction_size;
+use crate::utils::{
+    create_signer_from_path, get_batch_transaction_pda, send_and_confirm_transaction,
+    transaction_details,
+};
+
+/// Upper bound of close instructions per transaction, on top of the packet size limit.
+const MAX_CLOSES_PER_TRANSACTION: usize = 10;
+
+/// `getMultipleAccounts` accepts at most 100 keys.
+const ACCOUNTS_PER_REQUEST: usize = 100;
+
+#[derive(Args)]
+pub struct ReclaimRent {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair paying the transaction fees
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig to reclaim the rent of
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+/// A transaction index whose accounts can be closed.
+struct Reclaimable {
+    index: u64,
+    kind: &'static str,
+    reason: &'static str,
+    lamports: u64,
+    instructions: Vec<Instruction>,
+}
+
+impl ReclaimRent {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let fee_payer_keypair = create_signer_from_path(keypair).unwrap();
+
+        let fee_payer = fee_payer_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        let rent_collector = multisig_data
+            .rent_collector
+            .ok_or_else(|| eyre!("The multisig has no rent collector, rent cannot be reclaimed"))?;
+
+        let progress = ProgressBar::new_spinner().with_message(format!(
+            "Scanning {} transaction indexes...",
+            multisig_data.transaction_index
+        ));
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        let indexes: Vec<u64> = (1..=multisig_data.transaction_index).collect();
+        let mut reclaimable = Vec::new();
+        let mut skipped = 0;
+        for indexes in indexes.chunks(ACCOUNTS_PER_REQUEST / 2) {
+            let keys: Vec<Pubkey> = indexes
+                .iter()
+                .flat_map(|&index| {
+                    [
+                        get_transaction_pda(&multisig, index, Some(&program_id)).0,
+                        get_proposal_pda(&multisig, index, Some(&program_id)).0,
+                    ]
+                })
+                .collect();
+            let accounts = rpc_client.get_multiple_accounts(&keys).await?;
+
+            for (i, &index) in indexes.iter().enumerate() {
+                // Already closed.
+                let Some(transaction_account) = &accounts[2 * i] else {
+                    continue;
+                };
+                if transaction_account.owner != program_id {
+                    continue;
+                }
+                let proposal_account = accounts[2 * i + 1].as_ref();
+                let proposal = match proposal_account {
+                    Some(proposal_account) => {
+                        if proposal_account.owner != program_id {
+                            continue;
+                        }
+                        let Ok(proposal) =
+                            Proposal::try_deserialize(&mut proposal_account.data.as_slice())
+                        else {
+                            continue;
+                        };
+                        Some(proposal)
+                    }
+                    None => None,
+                };
+
+                let is_stale = index <= multisig_data.stale_transaction_index;
+                let reason = match proposal.as_ref().map(|proposal| &proposal.status) {
+                    Some(ProposalStatus::Executed { .. }) => "executed",
+                    Some(ProposalStatus::Cancelled { .. }) => "cancelled",
+                    Some(ProposalStatus::Rejected { .. }) => "rejected",
+                    #[allow(deprecated)]
+                    Some(ProposalStatus::Executing) => {
+                        skipped += 1;
+                        continue;
+                    }
+                    Some(_) if is_stale => "stale",
+                    // A stale transaction that never got a proposal can no longer get one, the
+                    // program closes it without the proposal account.
+                    None if is_stale => "no proposal",
+                    _ => {
+                        skipped += 1;
+                        continue;
+                    }
+                };
+                let is_approved = matches!(
+                    proposal.as_ref().map(|proposal| &proposal.status),
+                    Some(ProposalStatus::Approved { .. })
+                );
+                let transaction_pda = get_transaction_pda(&multisig, index, Some(&program_id)).0;
+                let proposal_pda = get_proposal_pda(&multisig, index, Some(&program_id)).0;
+                let mut lamports = transaction_account.lamports
+                    + proposal_account.map_or(0, |account| account.lamports);
+                let data = transaction_account.data.as_slice();
+
+                let (kind, instructions) =
+                    if ConfigTransaction::try_deserialize(&mut &data[..]).is_ok() {
+                        // Stale approved config transactions can no longer be executed, so they close.
+                        (
+                            "config",
+                            vec![config_transaction_accounts_close(
+                                ConfigTransactionAccountsCloseAccounts {
+                                    multisig,
+                                    proposal: proposal_pda,
+                                    transaction: transaction_pda,
+                                    rent_collector,
+                                    system_program: system_program::id(),
+                                },
+                                Some(program_id),
+                            )],
+                        )
+                    } else if is_approved {
+                        // Approved vault transactions and batches can still be executed.
+                        skipped += 1;
+                        continue;
+                    } else if VaultTransaction::try_deserialize(&mut &data[..]).is_ok() {
+                        (
+                            "vault",
+                            vec![vault_transaction_accounts_close(
+                                VaultTransactionAccountsCloseAccounts {
+                                    multisig,
+                                    proposal: proposal_pda,
+                                    transaction: transaction_pda,
+                                    rent_collector,
+                                    system_program: system_program::id(),
+                                },
+                                Some(program_id),
+                            )],
+                        )
+                    } else if let Ok(batch) = Batch::try_deserialize(&mut &data[..]) {
+                        let batch_transactions: Vec<Pubkey> = (1..=batch.size)
+                            .map(|step_index| {
+                                get_batch_transaction_pda(
+                                    &multisig,
+                                    index,
+                                    step_index,
+                                    Some(&program_id),
+                                )
+                                .0
+                            })
+                            .collect();
+                        for keys in batch_transactions.chunks(ACCOUNTS_PER_REQUEST) {
+                            lamports += rpc_client
+                                .get_multiple_accounts(keys)
+                                .await?
+                                .iter()
+                                .flatten()
+                                .map(|account| account.lamports)
+                                .sum::<u64>();
+                        }
+                        (
+                            "batch",
+                            batch_close_instructions(
+                                &multisig,
+                                index,
+                                batch.size,
+                                &rent_collector,
+                                &program_id,
+                            ),
+                        )
+                    } else {
+                        skipped += 1;
+                        continue;
+                    };
+
+                reclaimable.push(Reclaimable {
+                    index,
+                    kind,
+                    reason,
+                    lamports,
+                    instructions,
+                });
+            }
+        }
+        progress.finish_and_clear();
+
+        if reclaimable.is_empty() {
+            println!(
+                "Nothing to reclaim, {} open transactions can not be closed yet.",
+                skipped
+            );
+            return Ok(());
+        }
+
+        let total_lamports: u64 = reclaimable.iter().map(|r| r.lamports).sum();
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to reclaim the rent of closed transactions, please review the details:"
+                .yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", fee_payer);
+        println!();
+        println!("⚙️ Config Parameters");
+        println!("Multisig Key:       {}", multisig_pubkey);
+        println!("Rent Collector:       {}", rent_collector);
+        println!(
+            "Stale Transaction Index:       {}",
+            multisig_data.stale_transaction_index
+        );
+        println!();
+        println!("🧹 Closable Transactions");
+        for r in &reclaimable {
+            println!(
+                "  #{:<6} {:<8} {:<12} {} SOL",
+                r.index,
+                r.kind,
+                r.reason,
+                lamports_to_sol(r.lamports)
+            );
+        }
+        println!(
+            "Skipped:       {} (still open, approved or executing)",
+            skipped
+        );
+        println!(
+            "Total:       {}",
+            format!(
+                "{} SOL from {} transactions",
+                lamports_to_sol(total_lamports),
+                reclaimable.len()
+            )
+            .bright_green()
+        );
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let compute_budget =
+            ComputeBudgetInstruction::set_compute_unit_price(priority_fee_lamports.unwrap_or(5000));
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        // Pack the closes greedily, batches keep their order since they are sent sequentially.
+        let mut packed: Vec<Vec<Instruction>> = vec![vec![compute_budget.clone()]];
+        for instruction in reclaimable.into_iter().flat_map(|r| r.instructions) {
+            let current = packed.last_mut().unwrap();
+            current.push(instruction);
+            let message = Message::try_compile(&fee_payer, current, &[], blockhash)?;
+            if current.len() > MAX_CLOSES_PER_TRANSACTION + 1
+                || exceeds_transaction_size(&VersionedMessage::V0(message), 1)
+            {
+                let instruction = current.pop().unwrap();
+                packed.push(vec![compute_budget.clone(), instruction]);
+            }
+        }
+
+        for (i, instructions) in packed.iter().enumerate() {
+            let progress = ProgressBar::new_spinner().with_message(format!(
+                "Sending transaction {} of {}...",
+                i + 1,
+                packed.len()
+            ));
+            progress.enable_steady_tick(Duration::from_millis(100));
+
+            let blockhash = rpc_client
+                .get_latest_blockhash()
+                .await
+                .expect("Failed to get blockhash");
+
+            let message = Message::try_compile(&fee_payer, instructions, &[], blockhash).unwrap();
+
+            let transaction = VersionedTransaction::try_new(
+                VersionedMessage::V0(message),
+                &[&*fee_payer_keypair],
+            )
+            .expect("Failed to create transaction");
+            if let Err(e) = transaction_details(&transaction) {
+                eprintln!("❌ Error processing transaction details: {}", e);
+            }
+
+            let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+            progress.finish_and_clear();
+            println!(
+                "✅ Sent close transaction {} of {}. Signature: {}",
+                i + 1,
+                packed.len(),
+                signature.green()
+            );
+        }
+
+        println!(
+            "✅ Reclaimed {} SOL to {}",
+            lamports_to_sol(total_lamports),
+            rent_collector
+        );
+        Ok(())
+    }
+}
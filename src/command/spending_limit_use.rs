@@ -0,0 +1,283 @@
+use std::str::FromStr;
+use std::time::Duration;
+
+use clap::Args;
+use colored::Colorize;
+use dialoguer::Confirm;
+use eyre::eyre;
+use indicatif::ProgressBar;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account_idempotent;
+
+use squads_multisig::client::{
+    SpendingLimitUseAccounts, SpendingLimitUseArgs, get_multisig, spending_limit_use,
+};
+use squads_multisig::pda::get_vault_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+
+use crate::address_book::{describe, warn_unknown_recipient};
+use crate::command::display_spending_limit::{
+    fetch_spending_limit, fetch_unix_timestamp, print_spending_limit, spending_limit_window,
+};
+use crate::token_amount::{fetch_mint_info, parse_ui_amount};
+use crate::utils::{create_signer_from_path, send_and_confirm_transaction, transaction_details};
+
+#[derive(Args)]
+pub struct SpendingLimitUse {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// Path to the Keypair of a spending limit member
+    #[arg(long)]
+    keypair: String,
+
+    /// The multisig owning the spending limit
+    #[arg(long)]
+    multisig_pubkey: String,
+
+    /// The spending limit account to use
+    #[arg(long)]
+    spending_limit: String,
+
+    /// The wallet receiving the funds
+    #[arg(long)]
+    destination: String,
+
+    /// Amount to transfer in base units of the mint
+    #[arg(long, conflicts_with = "amount", required_unless_present = "amount")]
+    token_amount_u64: Option<u64>,
+
+    /// Amount to transfer in tokens, e.g. 12.5, converted with the decimals of the mint
+    #[arg(long)]
+    amount: Option<String>,
+
+    /// Memo to be included in the transaction
+    #[arg(long)]
+    memo: Option<String>,
+
+    #[arg(long)]
+    priority_fee_lamports: Option<u64>,
+}
+
+impl SpendingLimitUse {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            keypair,
+            multisig_pubkey,
+            spending_limit,
+            destination,
+            token_amount_u64,
+            amount,
+            memo,
+            priority_fee_lamports,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let member_keypair = create_signer_from_path(keypair).unwrap();
+
+        let member = member_keypair.pubkey();
+
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_url_clone = rpc_url.clone();
+        let rpc_client = RpcClient::new(rpc_url);
+
+        let multisig = Pubkey::from_str(&multisig_pubkey).expect("Invalid multisig address");
+        let spending_limit_key =
+            Pubkey::from_str(&spending_limit).expect("Invalid spending limit address");
+        let destination = Pubkey::from_str(&destination).expect("Invalid destination address");
+
+        let multisig_data = get_multisig(&rpc_client, &multisig).await?;
+        let spending_limit_data =
+            fetch_spending_limit(&rpc_client, &spending_limit_key, &program_id).await?;
+        let now = fetch_unix_timestamp(&rpc_client).await?;
+
+        // Run the program's checks locally so a member never signs a transaction bound to fail.
+        if spending_limit_data.multisig != multisig {
+            return Err(eyre!(
+                "Spending limit {} belongs to multisig {}",
+                spending_limit_key,
+                spending_limit_data.multisig
+            ));
+        }
+        if !multisig_data.members.iter().any(|m| m.key == member) {
+            return Err(eyre!("{} is not a member of the multisig", member));
+        }
+        if !spending_limit_data.members.contains(&member) {
+            return Err(eyre!(
+                "{} is not allowed to use this spending limit",
+                member
+            ));
+        }
+        if !spending_limit_data.destinations.is_empty()
+            && !spending_limit_data.destinations.contains(&destination)
+        {
+            return Err(eyre!(
+                "{} is not an allowed destination of this spending limit",
+                destination
+            ));
+        }
+        let mint_info = fetch_mint_info(&rpc_client, &spending_limit_data.mint).await?;
+        let amount = match (token_amount_u64, amount) {
+            (Some(token_amount_u64), _) => token_amount_u64,
+            (None, Some(amount)) => parse_ui_amount(&amount, mint_info.decimals)?,
+            (None, None) => return Err(eyre!("Either --amount or --token-amount-u64 is required")),
+        };
+        let (remaining_amount, _) = spending_limit_window(&spending_limit_data, now);
+        if amount > remaining_amount {
+            return Err(eyre!(
+                "Amount {} exceeds the remaining amount {} of the current period",
+                mint_info.display(amount),
+                mint_info.display(remaining_amount)
+            ));
+        }
+
+        let vault_pda = get_vault_pda(
+            &multisig,
+            spending_limit_data.vault_index,
+            Some(&program_id),
+        );
+
+        let mut instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+            priority_fee_lamports.unwrap_or(5000),
+        )];
+
+        let is_sol = spending_limit_data.mint == Pubkey::default();
+        let accounts = if is_sol {
+            SpendingLimitUseAccounts {
+                multisig,
+                member,
+                spending_limit: spending_limit_key,
+                vault: vault_pda.0,
+                destination,
+                system_program: Some(system_program::id()),
+                mint: None,
+                vault_token_account: None,
+                destination_token_account: None,
+                token_program: None,
+            }
+        } else {
+            let token_program_id = mint_info.token_program;
+            let vault_token_account = get_associated_token_address_with_program_id(
+                &vault_pda.0,
+                &spending_limit_data.mint,
+                &token_program_id,
+            );
+            let destination_token_account = get_associated_token_address_with_program_id(
+                &destination,
+                &spending_limit_data.mint,
+                &token_program_id,
+            );
+            if rpc_client
+                .get_account(&destination_token_account)
+                .await
+                .is_err()
+            {
+                instructions.push(create_associated_token_account_idempotent(
+                    &member,
+                    &destination,
+                    &spending_limit_data.mint,
+                    &token_program_id,
+                ));
+            }
+            SpendingLimitUseAccounts {
+                multisig,
+                member,
+                spending_limit: spending_limit_key,
+                vault: vault_pda.0,
+                destination,
+                system_program: None,
+                mint: Some(spending_limit_data.mint),
+                vault_token_account: Some(vault_token_account),
+                destination_token_account: Some(destination_token_account),
+                token_program: Some(token_program_id),
+            }
+        };
+
+        println!();
+        println!(
+            "{}",
+            "👀 You're about to use a spending limit, please review the details:".yellow()
+        );
+        println!();
+        println!("RPC Cluster URL:   {}", rpc_url_clone);
+        println!("Program ID:        {}", program_id);
+        println!("Your Public Key:       {}", member);
+        println!();
+        println!("⚙️ Spending Limit");
+        println!("Spending Limit:     {}", spending_limit_key);
+        print_spending_limit(&spending_limit_data, &mint_info, now);
+        println!();
+        println!("💸 Transfer");
+        println!("Vault:              {}", vault_pda.0);
+        println!("Destination:        {}", describe(&destination));
+        println!(
+            "Amount:             {}",
+            mint_info.display(amount).bright_cyan()
+        );
+        println!(
+            "Remaining after:    {}",
+            mint_info.display(remaining_amount - amount)
+        );
+        warn_unknown_recipient(&destination);
+        println!();
+
+        let proceed = Confirm::new()
+            .with_prompt("Do you want to proceed?")
+            .default(false)
+            .interact()?;
+        if !proceed {
+            println!("OK, aborting.");
+            return Ok(());
+        }
+        println!();
+
+        let progress = ProgressBar::new_spinner().with_message("Sending transaction...");
+        progress.enable_steady_tick(Duration::from_millis(100));
+
+        instructions.push(spending_limit_use(
+            accounts,
+            SpendingLimitUseArgs {
+                amount,
+                decimals: mint_info.decimals,
+                memo,
+            },
+            Some(program_id),
+        ));
+
+        let blockhash = rpc_client
+            .get_latest_blockhash()
+            .await
+            .expect("Failed to get blockhash");
+
+        let message = Message::try_compile(&member, &instructions, &[], blockhash).unwrap();
+
+        let transaction =
+            VersionedTransaction::try_new(VersionedMessage::V0(message), &[&*member_keypair])
+                .expect("Failed to create transaction");
+        if let Err(e) = transaction_details(&transaction) {
+            eprintln!("❌ Error processing transaction details: {}", e);
+        }
+
+        let signature = send_and_confirm_transaction(&transaction, &rpc_client).await?;
+
+        println!("✅ Used Spending Limit. Signature: {}", signature.green());
+        Ok(())
+    }
+}
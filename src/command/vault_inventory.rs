@@ -0,0 +1,226 @@
+use clap::Args;
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::{Value, json};
+use solana_program::program_pack::Pack;
+use solana_sdk::native_token::lamports_to_sol;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::pda::get_vault_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::solana_client::rpc_request::TokenAccountsFilter;
+use std::str::FromStr;
+
+use crate::output;
+use crate::token_amount::TOKEN_2022_PROGRAM_ID;
+
+/// `getMultipleAccounts` accepts at most 100 keys.
+const ACCOUNTS_PER_REQUEST: usize = 100;
+
+#[derive(Args)]
+pub struct VaultInventory {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The multisig owning the vaults
+    #[arg(long)]
+    multisig_address: String,
+
+    /// First vault index to scan
+    #[arg(long, default_value_t = 0)]
+    from_vault_index: u8,
+
+    /// Last vault index to scan
+    #[arg(long, default_value_t = 9)]
+    to_vault_index: u8,
+}
+
+impl VaultInventory {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            multisig_address,
+            from_vault_index,
+            to_vault_index,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let multisig_address =
+            Pubkey::from_str(&multisig_address).expect("Invalid multisig address");
+
+        if from_vault_index > to_vault_index {
+            return Err(eyre!(
+                "--from-vault-index must not be greater than --to-vault-index"
+            ));
+        }
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let mut vaults = Vec::new();
+        for vault_index in from_vault_index..=to_vault_index {
+            let vault = get_vault_pda(&multisig_address, vault_index, Some(&program_id)).0;
+            vaults.push(fetch_vault_inventory(&rpc_client, vault_index, &vault).await?);
+        }
+
+        output::set("vaults", Value::Array(vaults.clone()));
+
+        let mut empty = 0;
+        for vault in &vaults {
+            let token_accounts = vault["token_accounts"].as_array().unwrap();
+            if vault["lamports"].as_u64() == Some(0) && token_accounts.is_empty() {
+                empty += 1;
+                continue;
+            }
+            println!();
+            println!(
+                "Vault #{} -> {}",
+                vault["vault_index"],
+                vault["address"].as_str().unwrap()
+            );
+            println!(
+                "  SOL: {}",
+                lamports_to_sol(vault["lamports"].as_u64().unwrap_or_default())
+            );
+            for account in token_accounts {
+                print_token_account(account);
+            }
+        }
+        if empty > 0 {
+            println!();
+            println!(
+                "{}",
+                format!("{} empty vaults skipped.", empty).bright_black()
+            );
+        }
+
+        Ok(())
+    }
+}
+
+/// SOL balance and every SPL Token / Token-2022 account owned by the vault, as JSON.
+pub async fn fetch_vault_inventory(
+    rpc_client: &RpcClient,
+    vault_index: u8,
+    vault: &Pubkey,
+) -> eyre::Result<Value> {
+    let lamports = rpc_client.get_balance(vault).await?;
+
+    let mut token_accounts = Vec::new();
+    for token_program in [
+        spl_token::id(),
+        Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap(),
+    ] {
+        let accounts = rpc_client
+            .get_token_accounts_by_owner(vault, TokenAccountsFilter::ProgramId(token_program))
+            .await
+            .map_err(|e| eyre!("Failed to get token accounts of {}: {}", vault, e))?;
+        for keyed_account in accounts {
+            // The RPC returns the jsonParsed encoding: {"program", "parsed": {"info"}, "space"}.
+            let data = serde_json::to_value(&keyed_account.account.data)?;
+            let info = &data["parsed"]["info"];
+            let amount = &info["tokenAmount"];
+            token_accounts.push(json!({
+                "address": keyed_account.pubkey,
+                "token_program": token_program.to_string(),
+                "mint": info["mint"],
+                "amount": amount["amount"],
+                "decimals": amount["decimals"],
+                "ui_amount": amount["uiAmountString"],
+                "delegate": info["delegate"],
+                "delegated_amount": info["delegatedAmount"]["amount"],
+                "state": info["state"],
+                "close_authority": info["closeAuthority"],
+            }));
+        }
+    }
+
+    // The freeze authority lives on the mint, look it up once per mint.
+    let mut mints: Vec<Pubkey> = token_accounts
+        .iter()
+        .filter_map(|account| account["mint"].as_str())
+        .filter_map(|mint| Pubkey::from_str(mint).ok())
+        .collect();
+    mints.sort();
+    mints.dedup();
+    for mints in mints.chunks(ACCOUNTS_PER_REQUEST) {
+        let mint_accounts = rpc_client.get_multiple_accounts(mints).await?;
+        for (mint, mint_account) in mints.iter().zip(mint_accounts) {
+            // Token-2022 mints share the base layout, extensions follow after it.
+            let freeze_authority = mint_account
+                .filter(|account| account.data.len() >= spl_token::state::Mint::LEN)
+                .and_then(|account| {
+                    spl_token::state::Mint::unpack_from_slice(
+                        &account.data[..spl_token::state::Mint::LEN],
+                    )
+                    .ok()
+                })
+                .and_then(|mint| Option::<Pubkey>::from(mint.freeze_authority));
+            for account in token_accounts
+                .iter_mut()
+                .filter(|account| account["mint"] == mint.to_string())
+            {
+                account["freeze_authority"] = json!(freeze_authority.map(|k| k.to_string()));
+            }
+        }
+    }
+
+    Ok(json!({
+        "vault_index": vault_index,
+        "address": vault.to_string(),
+        "lamports": lamports,
+        "token_accounts": token_accounts,
+    }))
+}
+
+fn print_token_account(account: &Value) {
+    let program = if account["token_program"] == TOKEN_2022_PROGRAM_ID {
+        "Token-2022"
+    } else {
+        "SPL Token"
+    };
+    println!(
+        "  {} {} ({}, {} decimals)",
+        account["ui_amount"].as_str().unwrap_or("?").bright_green(),
+        account["mint"].as_str().unwrap_or("?"),
+        program,
+        account["decimals"]
+    );
+    println!(
+        "    Account:          {}",
+        account["address"].as_str().unwrap_or("?")
+    );
+    if let Some(delegate) = account["delegate"].as_str() {
+        println!(
+            "    {}",
+            format!(
+                "Delegate:         {} ({} base units)",
+                delegate,
+                account["delegated_amount"].as_str().unwrap_or("?")
+            )
+            .yellow()
+        );
+    }
+    if account["state"] == "frozen" {
+        println!("    {}", "State:            frozen".red());
+    }
+    if let Some(freeze_authority) = account["freeze_authority"].as_str() {
+        println!("    Freeze Authority: {}", freeze_authority);
+    }
+    if let Some(close_authority) = account["close_authority"].as_str() {
+        println!(
+            "    {}",
+            format!("Close Authority:  {}", close_authority).yellow()
+        );
+    }
+}
@@ -0,0 +1,416 @@
+use std::str::FromStr;
+
+use clap::{Args, Subcommand};
+use colored::Colorize;
+use eyre::eyre;
+use serde_json::json;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::{AccountDeserialize, AnchorSerialize};
+use squads_multisig::pda::{get_transaction_pda, get_vault_pda};
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::state::VaultTransaction;
+use squads_multisig::state::VaultTransactionMessage;
+
+use crate::address_book::describe;
+use crate::command::initiate_program_upgrade::program_upgrade_message;
+use crate::command::initiate_transfer::transfer_message;
+use crate::fingerprint::{
+    compile_vault_message, fingerprint_json, format_fingerprint, vault_transaction_fingerprint,
+};
+use crate::output;
+use crate::token_amount::{fetch_mint_info, parse_ui_amount};
+
+/// Rebuilds the message an `initiate-*` command would propose and compares it byte for byte
+/// with the vault transaction on chain
+#[derive(Args)]
+pub struct VerifyProposal {
+    /// RPC URL
+    #[arg(long)]
+    rpc_url: Option<String>,
+
+    /// Multisig Program ID
+    #[arg(long)]
+    program_id: Option<String>,
+
+    /// The multisig where the transaction has been proposed
+    #[arg(long)]
+    multisig_address: String,
+
+    /// Index of the vault transaction to verify
+    #[arg(long)]
+    transaction_index: u64,
+
+    /// The action the proposal is expected to perform
+    #[command(subcommand)]
+    action: ExpectedAction,
+}
+
+#[derive(Subcommand)]
+pub enum ExpectedAction {
+    /// The arguments of `initiate-transfer`
+    Transfer(ExpectedTransfer),
+    /// The arguments of `initiate-program-upgrade`
+    ProgramUpgrade(ExpectedProgramUpgrade),
+}
+
+#[derive(Args)]
+pub struct ExpectedTransfer {
+    /// Token program ID. Defaults to the owner of the mint.
+    #[arg(long)]
+    token_program_id: Option<String>,
+
+    #[arg(long)]
+    token_mint_address: String,
+
+    /// Amount in base units of the mint
+    #[arg(long, conflicts_with = "amount", required_unless_present = "amount")]
+    token_amount_u64: Option<u64>,
+
+    /// Amount in tokens, e.g. 12.5, converted with the decimals of the mint
+    #[arg(long)]
+    amount: Option<String>,
+
+    /// The recipient of the Token(s)
+    #[arg(long)]
+    recipient: String,
+
+    #[arg(long)]
+    vault_index: u8,
+}
+
+#[derive(Args)]
+pub struct ExpectedProgramUpgrade {
+    /// The program to upgrade
+    #[arg(long)]
+    program_to_upgrade_id: String,
+
+    /// The buffer holding the new program data
+    #[arg(long)]
+    buffer_address: String,
+
+    /// The spill address
+    #[arg(long)]
+    spill_address: String,
+
+    #[arg(long)]
+    vault_index: u8,
+}
+
+impl VerifyProposal {
+    pub async fn execute(self) -> eyre::Result<()> {
+        let Self {
+            rpc_url,
+            program_id,
+            multisig_address,
+            transaction_index,
+            action,
+        } = self;
+
+        let program_id =
+            program_id.unwrap_or_else(|| "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf".to_string());
+
+        let program_id = Pubkey::from_str(&program_id).expect("Invalid program ID");
+
+        let multisig_address =
+            Pubkey::from_str(&multisig_address).expect("Invalid multisig address");
+
+        // Initialize RPC client
+        let rpc_url = rpc_url.unwrap_or_else(|| "https://api.mainnet-beta.solana.com".to_string());
+        let rpc_client = RpcClient::new(rpc_url.to_string());
+
+        let transaction_pda =
+            get_transaction_pda(&multisig_address, transaction_index, Some(&program_id)).0;
+        let vault_transaction =
+            fetch_vault_transaction(&rpc_client, &transaction_pda, &program_id).await?;
+
+        let (description, vault_index, expected) = match action {
+            ExpectedAction::Transfer(transfer) => {
+                let token_mint = Pubkey::from_str(&transfer.token_mint_address)
+                    .expect("Invalid Token Mint Address");
+                let recipient =
+                    Pubkey::from_str(&transfer.recipient).expect("Invalid recipient address");
+                let mint_info = fetch_mint_info(&rpc_client, &token_mint).await?;
+                if let Some(expected) = transfer.token_program_id {
+                    let expected = Pubkey::from_str(&expected).expect("Invalid token program ID");
+                    if expected != mint_info.token_program {
+                        return Err(eyre!(
+                            "The mint is owned by {}, not by the token program {}",
+                            mint_info.token_program,
+                            expected
+                        ));
+                    }
+                }
+                let amount = match (transfer.token_amount_u64, transfer.amount) {
+                    (Some(token_amount_u64), _) => token_amount_u64,
+                    (None, Some(amount)) => parse_ui_amount(&amount, mint_info.decimals)?,
+                    (None, None) => {
+                        return Err(eyre!("Either --amount or --token-amount-u64 is required"));
+                    }
+                };
+                let vault =
+                    get_vault_pda(&multisig_address, transfer.vault_index, Some(&program_id)).0;
+                (
+                    format!(
+                        "Transfer {} from vault #{} to {}",
+                        mint_info.display(amount),
+                        transfer.vault_index,
+                        describe(&recipient)
+                    ),
+                    transfer.vault_index,
+                    transfer_message(&vault, &mint_info, &recipient, amount),
+                )
+            }
+            ExpectedAction::ProgramUpgrade(upgrade) => {
+                let program_to_upgrade = Pubkey::from_str(&upgrade.program_to_upgrade_id)
+                    .expect("Invalid to upgrade program ID");
+                let buffer_address =
+                    Pubkey::from_str(&upgrade.buffer_address).expect("Invalid buffer address");
+                let spill_address =
+                    Pubkey::from_str(&upgrade.spill_address).expect("Invalid spill address");
+                let vault =
+                    get_vault_pda(&multisig_address, upgrade.vault_index, Some(&program_id)).0;
+                (
+                    format!(
+                        "Upgrade program {} from buffer {} by vault #{}, spill to {}",
+                        describe(&program_to_upgrade),
+                        buffer_address,
+                        upgrade.vault_index,
+                        describe(&spill_address)
+                    ),
+                    upgrade.vault_index,
+                    program_upgrade_message(
+                        &vault,
+                        &program_to_upgrade,
+                        &buffer_address,
+                        &spill_address,
+                    ),
+                )
+            }
+        };
+        let expected = compile_vault_message(expected)?;
+
+        println!("Transaction -> {}", transaction_pda);
+        println!(
+            "Proposed by:        {}",
+            describe(&vault_transaction.creator)
+        );
+        println!("Expected action:    {}", description.bright_cyan());
+        println!();
+
+        let mut differences = Vec::new();
+        if vault_transaction.vault_index != vault_index {
+            differences.push(format!(
+                "Vault index: expected {}, found {}",
+                vault_index, vault_transaction.vault_index
+            ));
+        }
+        // The initiate commands never request ephemeral signers.
+        if !vault_transaction.ephemeral_signer_bumps.is_empty() {
+            differences.push(format!(
+                "Ephemeral signers: expected none, found {}",
+                vault_transaction.ephemeral_signer_bumps.len()
+            ));
+        }
+        differences.extend(diff_messages(&expected, &vault_transaction.message));
+
+        // The fingerprint of the expected message, signers compare it with `display-transaction`.
+        let fingerprint = vault_transaction_fingerprint(
+            &multisig_address,
+            transaction_index,
+            vault_index,
+            &expected,
+        );
+        let expected_bytes = expected.try_to_vec()?;
+        let actual_bytes = vault_transaction.message.try_to_vec()?;
+        let matches = differences.is_empty() && expected_bytes == actual_bytes;
+        output::set(
+            "verification",
+            json!({
+                "transaction": transaction_pda.to_string(),
+                "index": transaction_index,
+                "expected_action": description,
+                "matches": matches,
+                "expected_fingerprint": fingerprint_json(&fingerprint),
+                "expected_message": hex::encode(&expected_bytes),
+                "actual_message": hex::encode(&actual_bytes),
+                "differences": differences,
+            }),
+        );
+
+        if matches {
+            println!(
+                "{}",
+                format!(
+                    "✅ MATCH: the on-chain message is byte for byte the expected one ({} bytes).",
+                    actual_bytes.len()
+                )
+                .green()
+                .bold()
+            );
+            println!(
+                "Fingerprint:        {}",
+                format_fingerprint(&fingerprint).bright_green().bold()
+            );
+            return Ok(());
+        }
+
+        println!(
+            "{}",
+            "❌ MISMATCH: do NOT approve this proposal.".red().bold()
+        );
+        for difference in &differences {
+            println!("  - {}", difference.red());
+        }
+        Err(eyre!(
+            "The message of transaction #{} does not match the expected action",
+            transaction_index
+        ))
+    }
+}
+
+/// Loads a vault transaction, rejecting accounts not owned by the multisig program.
+pub async fn fetch_vault_transaction(
+    rpc_client: &RpcClient,
+    transaction: &Pubkey,
+    program_id: &Pubkey,
+) -> eyre::Result<VaultTransaction> {
+    let account = rpc_client
+        .get_account(transaction)
+        .await
+        .map_err(|e| eyre!("Failed to get transaction account: {}", e))?;
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+            transaction,
+            account.owner
+        ));
+    }
+    VaultTransaction::try_deserialize(&mut account.data.as_slice())
+        .map_err(|_| eyre!("{} is not a vault transaction", transaction))
+}
+
+/// Every field in which `actual` deviates from `expected`, one line each.
+pub fn diff_messages(
+    expected: &VaultTransactionMessage,
+    actual: &VaultTransactionMessage,
+) -> Vec<String> {
+    let mut differences = Vec::new();
+    let mut field = |name: &str, expected: u8, actual: u8| {
+        if expected != actual {
+            differences.push(format!("{}: expected {}, found {}", name, expected, actual));
+        }
+    };
+    field("Signers", expected.num_signers, actual.num_signers);
+    field(
+        "Writable signers",
+        expected.num_writable_signers,
+        actual.num_writable_signers,
+    );
+    field(
+        "Writable non-signers",
+        expected.num_writable_non_signers,
+        actual.num_writable_non_signers,
+    );
+
+    let key = |keys: &[Pubkey], index: usize| {
+        keys.get(index)
+            .map(describe)
+            .unwrap_or_else(|| "nothing".to_string())
+    };
+    for index in 0..expected.account_keys.len().max(actual.account_keys.len()) {
+        if expected.account_keys.get(index) != actual.account_keys.get(index) {
+            differences.push(format!(
+                "Account #{}: expected {}, found {}",
+                index,
+                key(&expected.account_keys, index),
+                key(&actual.account_keys, index)
+            ));
+        }
+    }
+
+    if expected.instructions.len() != actual.instructions.len() {
+        differences.push(format!(
+            "Instructions: expected {}, found {}",
+            expected.instructions.len(),
+            actual.instructions.len()
+        ));
+    }
+    for (i, (expected, actual)) in expected
+        .instructions
+        .iter()
+        .zip(&actual.instructions)
+        .enumerate()
+    {
+        if expected.program_id_index != actual.program_id_index {
+            differences.push(format!(
+                "Instruction #{} program: expected account #{}, found account #{}",
+                i + 1,
+                expected.program_id_index,
+                actual.program_id_index
+            ));
+        }
+        if expected.account_indexes != actual.account_indexes {
+            differences.push(format!(
+                "Instruction #{} accounts: expected {:?}, found {:?}",
+                i + 1,
+                expected.account_indexes,
+                actual.account_indexes
+            ));
+        }
+        if expected.data != actual.data {
+            let offset = expected
+                .data
+                .iter()
+                .zip(&actual.data)
+                .position(|(a, b)| a != b)
+                .unwrap_or_else(|| expected.data.len().min(actual.data.len()));
+            differences.push(format!(
+                "Instruction #{} data differs from byte {}: expected {}, found {}",
+                i + 1,
+                offset,
+                hex::encode(&expected.data),
+                hex::encode(&actual.data)
+            ));
+        }
+    }
+
+    for lookup in &actual.address_table_lookups {
+        if !expected
+            .address_table_lookups
+            .iter()
+            .any(|expected| expected.account_key == lookup.account_key)
+        {
+            differences.push(format!(
+                "Unexpected address lookup table {} (writable {:?}, readonly {:?})",
+                lookup.account_key, lookup.writable_indexes, lookup.readonly_indexes
+            ));
+        }
+    }
+    for lookup in &expected.address_table_lookups {
+        match actual
+            .address_table_lookups
+            .iter()
+            .find(|actual| actual.account_key == lookup.account_key)
+        {
+            None => differences.push(format!(
+                "Missing address lookup table {}",
+                lookup.account_key
+            )),
+            Some(actual)
+                if actual.writable_indexes != lookup.writable_indexes
+                    || actual.readonly_indexes != lookup.readonly_indexes =>
+            {
+                differences.push(format!(
+                    "Address lookup table {}: expected writable {:?} readonly {:?}, found writable {:?} readonly {:?}",
+                    lookup.account_key,
+                    lookup.writable_indexes,
+                    lookup.readonly_indexes,
+                    actual.writable_indexes,
+                    actual.readonly_indexes
+                ))
+            }
+            Some(_) => {}
+        }
+    }
+    differences
+}
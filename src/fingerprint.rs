@@ -0,0 +1,118 @@
+use eyre::eyre;
+use serde_json::{Value, json};
+use solana_sdk::hash::hashv;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::{AccountDeserialize, AnchorSerialize, Discriminator};
+use squads_multisig::pda::get_transaction_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::TransactionMessage;
+use squads_multisig::squads_multisig_program::state::VaultTransaction;
+use squads_multisig::state::{ConfigAction, ConfigTransaction, VaultTransactionMessage};
+
+/// Bumped whenever the hashed content changes, old fingerprints must never match new ones.
+const VAULT_TRANSACTION_DOMAIN: &[u8] = b"secure-squads:vault-transaction:v1";
+const CONFIG_TRANSACTION_DOMAIN: &[u8] = b"secure-squads:config-transaction:v1";
+
+/// Hash of everything a vault transaction executes, like the Safe transaction hash: two signers
+/// seeing the same fingerprint are looking at the same transaction.
+pub fn vault_transaction_fingerprint(
+    multisig: &Pubkey,
+    transaction_index: u64,
+    vault_index: u8,
+    message: &VaultTransactionMessage,
+) -> [u8; 32] {
+    let message = message
+        .try_to_vec()
+        .expect("Failed to serialize the message");
+    hashv(&[
+        VAULT_TRANSACTION_DOMAIN,
+        multisig.as_ref(),
+        &transaction_index.to_le_bytes(),
+        &[vault_index],
+        &message,
+    ])
+    .to_bytes()
+}
+
+/// The message as `vault_transaction_create` stores it on chain.
+pub fn compile_vault_message(message: TransactionMessage) -> eyre::Result<VaultTransactionMessage> {
+    VaultTransactionMessage::try_from(message)
+        .map_err(|e| eyre!("Failed to compile the vault transaction message: {:?}", e))
+}
+
+/// Hash of the actions of a config transaction, see [`vault_transaction_fingerprint`].
+pub fn config_transaction_fingerprint(
+    multisig: &Pubkey,
+    transaction_index: u64,
+    actions: &[ConfigAction],
+) -> [u8; 32] {
+    let actions = actions
+        .try_to_vec()
+        .expect("Failed to serialize the config actions");
+    hashv(&[
+        CONFIG_TRANSACTION_DOMAIN,
+        multisig.as_ref(),
+        &transaction_index.to_le_bytes(),
+        &actions,
+    ])
+    .to_bytes()
+}
+
+/// The first 128 bits as eight groups of four hex digits, short enough to read over a call:
+/// `3F9A 21C4 7B0E D5A2 9C11 04FE 88B3 6D20`.
+pub fn format_fingerprint(fingerprint: &[u8; 32]) -> String {
+    fingerprint[..16]
+        .chunks(2)
+        .map(hex::encode_upper)
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+pub fn fingerprint_json(fingerprint: &[u8; 32]) -> Value {
+    json!({
+        "hash": hex::encode(fingerprint),
+        "short": format_fingerprint(fingerprint),
+    })
+}
+
+/// Fingerprint of the vault or config transaction at `transaction_index`, `None` for batches.
+pub async fn fetch_transaction_fingerprint(
+    rpc_client: &RpcClient,
+    multisig: &Pubkey,
+    transaction_index: u64,
+    program_id: &Pubkey,
+) -> eyre::Result<Option<[u8; 32]>> {
+    let transaction_pda = get_transaction_pda(multisig, transaction_index, Some(program_id)).0;
+    let account = rpc_client
+        .get_account(&transaction_pda)
+        .await
+        .map_err(|e| eyre!("Failed to get transaction account: {}", e))?;
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the multisig program",
+            transaction_pda,
+            account.owner
+        ));
+    }
+    let discriminator = account.data.get(..8).unwrap_or_default();
+    if discriminator == VaultTransaction::DISCRIMINATOR {
+        let transaction = VaultTransaction::try_deserialize(&mut account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a vault transaction", transaction_pda))?;
+        return Ok(Some(vault_transaction_fingerprint(
+            multisig,
+            transaction.index,
+            transaction.vault_index,
+            &transaction.message,
+        )));
+    }
+    if discriminator == ConfigTransaction::DISCRIMINATOR {
+        let transaction = ConfigTransaction::try_deserialize(&mut account.data.as_slice())
+            .map_err(|_| eyre!("{} is not a config transaction", transaction_pda))?;
+        return Ok(Some(config_transaction_fingerprint(
+            multisig,
+            transaction.index,
+            &transaction.actions,
+        )));
+    }
+    Ok(None)
+}
@@ -1,21 +1,34 @@
 //#![allow(warnings)]
-use clap::Parser;
+use clap::{CommandFactory, FromArgMatches, Parser};
 use command::Command;
+use output::OutputFormat;
+mod address_book;
 mod command;
+mod fingerprint;
+mod output;
 mod squads_decoder;
+mod token_amount;
+mod transaction_buffer;
 pub mod utils;
 
 #[derive(Parser)]
 struct App {
     #[command(subcommand)]
     command: Command,
+
+    /// Output format, json prints one versioned JSON document on stdout
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    output: OutputFormat,
 }
 
 #[tokio::main]
 async fn main() -> eyre::Result<()> {
-    let app = App::parse();
+    let matches = App::command().get_matches();
+    let command_name = matches.subcommand_name().unwrap_or_default().to_string();
+    let app = App::from_arg_matches(&matches)?;
+    output::init(app.output);
 
-    match app.command {
+    let result = match app.command {
         Command::MultisigCreate(command) => command.execute().await,
         Command::DisplayVault(command) => command.execute().await,
         Command::InitiateTransfer(command) => command.execute().await,
@@ -24,5 +37,34 @@ async fn main() -> eyre::Result<()> {
         Command::VaultTransactionAccountsClose(command) => command.execute().await,
         Command::DisplayTransaction(command) => command.execute().await,
         Command::InitiateProgramUpgrade(command) => command.execute().await,
-    }
+        Command::ConfigTransactionCreate(command) => command.execute().await,
+        Command::ConfigTransactionExecute(command) => command.execute().await,
+        Command::DisplaySpendingLimit(command) => command.execute().await,
+        Command::MultisigAddSpendingLimit(command) => command.execute().await,
+        Command::MultisigRemoveSpendingLimit(command) => command.execute().await,
+        Command::SpendingLimitUse(command) => command.execute().await,
+        Command::BatchCreate(command) => command.execute().await,
+        Command::BatchAddTransaction(command) => command.execute().await,
+        Command::BatchExecuteTransaction(command) => command.execute().await,
+        Command::DisplayBatch(command) => command.execute().await,
+        Command::BatchAccountsClose(command) => command.execute().await,
+        Command::ReclaimRent(command) => command.execute().await,
+        Command::ProgramConfig(command) => command.execute().await,
+        Command::MultisigAddMember(command) => command.execute().await,
+        Command::MultisigRemoveMember(command) => command.execute().await,
+        Command::MultisigChangeThreshold(command) => command.execute().await,
+        Command::MultisigSetTimeLock(command) => command.execute().await,
+        Command::MultisigSetConfigAuthority(command) => command.execute().await,
+        Command::MultisigSetRentCollector(command) => command.execute().await,
+        Command::DisplayMultisig(command) => command.execute().await,
+        Command::ListProposals(command) => command.execute().await,
+        Command::VaultInventory(command) => command.execute().await,
+        Command::AddressBook(command) => command.execute().await,
+        Command::VerifyProposal(command) => command.execute().await,
+        Command::Idl(command) => command.execute().await,
+        Command::InspectAccount(command) => command.execute().await,
+    };
+
+    output::finish(&command_name, &result);
+    result
 }
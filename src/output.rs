@@ -0,0 +1,131 @@
+use std::fs::File;
+use std::io::Write;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use clap::ValueEnum;
+use lazy_static::lazy_static;
+use serde_json::{Map, Value, json};
+
+/// Bumped whenever a field of the JSON document is renamed, removed or changes its meaning.
+pub const SCHEMA_VERSION: u32 = 2;
+
+#[derive(Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Colored, human readable text
+    #[default]
+    Text,
+    /// A single versioned JSON document on stdout, the human readable text goes to stderr
+    Json,
+}
+
+static JSON: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref RESULT: Mutex<Map<String, Value>> = Mutex::new(Map::new());
+    static ref ERRORS: Mutex<Vec<Value>> = Mutex::new(Vec::new());
+    static ref STDOUT: Mutex<Option<File>> = Mutex::new(None);
+}
+
+/// Selects the output format, must run before anything is printed.
+pub fn init(format: OutputFormat) {
+    if format != OutputFormat::Json {
+        return;
+    }
+    JSON.store(true, Ordering::SeqCst);
+    colored::control::set_override(false);
+    match redirect_stdout_to_stderr() {
+        Ok(stdout) => *STDOUT.lock().unwrap() = stdout,
+        Err(e) => eprintln!("Failed to redirect the human readable output: {}", e),
+    }
+}
+
+pub fn is_json() -> bool {
+    JSON.load(Ordering::SeqCst)
+}
+
+/// Sets `key` of the result object of the JSON document.
+pub fn set(key: &str, value: Value) {
+    if is_json() {
+        RESULT.lock().unwrap().insert(key.to_string(), value);
+    }
+}
+
+/// Appends `value` to the array `key` of the result object, e.g. every sent signature.
+pub fn push(key: &str, value: Value) {
+    if is_json() {
+        let mut result = RESULT.lock().unwrap();
+        match result
+            .entry(key.to_string())
+            .or_insert_with(|| Value::Array(Vec::new()))
+        {
+            Value::Array(values) => values.push(value),
+            other => *other = Value::Array(vec![other.take(), value]),
+        }
+    }
+}
+
+/// Records a non fatal error, fatal ones are taken from the command result in [`finish`].
+pub fn error(message: impl ToString) {
+    if is_json() {
+        ERRORS
+            .lock()
+            .unwrap()
+            .push(Value::String(message.to_string()));
+    }
+}
+
+/// Writes the JSON document of the finished command to the original stdout.
+pub fn finish(command: &str, result: &eyre::Result<()>) {
+    if !is_json() {
+        return;
+    }
+    let mut errors = std::mem::take(&mut *ERRORS.lock().unwrap());
+    if let Err(e) = result {
+        errors.push(Value::String(format!("{:#}", e)));
+    }
+    let document = json!({
+        "version": SCHEMA_VERSION,
+        "command": command,
+        "status": if result.is_ok() { "ok" } else { "error" },
+        "result": std::mem::take(&mut *RESULT.lock().unwrap()),
+        "errors": errors,
+    });
+    let document = serde_json::to_string_pretty(&document).unwrap();
+
+    let _ = std::io::stdout().flush();
+    match STDOUT.lock().unwrap().as_mut() {
+        Some(stdout) => {
+            let _ = writeln!(stdout, "{}", document);
+        }
+        None => println!("{}", document),
+    }
+}
+
+/// Points fd 1 at stderr so every `println!` becomes diagnostics, and returns the original stdout.
+#[cfg(unix)]
+fn redirect_stdout_to_stderr() -> std::io::Result<Option<File>> {
+    use std::os::fd::{AsRawFd, FromRawFd};
+
+    let stdout = std::io::stdout().as_raw_fd();
+    let stderr = std::io::stderr().as_raw_fd();
+    // SAFETY: plain fd duplication, the duplicated fd is owned by the returned File.
+    unsafe {
+        let original = libc::dup(stdout);
+        if original < 0 {
+            return Err(std::io::Error::last_os_error());
+        }
+        if libc::dup2(stderr, stdout) < 0 {
+            let e = std::io::Error::last_os_error();
+            libc::close(original);
+            return Err(e);
+        }
+        Ok(Some(File::from_raw_fd(original)))
+    }
+}
+
+/// Other platforms keep the text on stdout, the document is printed last.
+#[cfg(not(unix))]
+fn redirect_stdout_to_stderr() -> std::io::Result<Option<File>> {
+    Ok(None)
+}
@@ -0,0 +1,61 @@
+use chainparser::ChainparserDeserialize;
+use chainparser::errors::ChainparserError;
+use serde_json::Value;
+use solana_sdk::pubkey::Pubkey;
+
+use super::idl_registry::{IdlRegistry, IdlSource};
+use super::instruction_mapper::deserialize_defined_type;
+
+/// Length of the discriminator Anchor prefixes account data with.
+const ACCOUNT_DISCRIMINATOR_LEN: usize = 8;
+
+pub struct AccountMapResult {
+    pub program_name: String,
+    /// Where the IDL the account was decoded with came from.
+    pub idl_source: IdlSource,
+    pub account_name: String,
+    pub decoded: Value,
+    /// Bytes after the decoded layout, usually space reserved for future fields.
+    pub trailing_bytes: usize,
+}
+
+/// Decodes the data of an account owned by `owner` with the IDL registered for it, the account
+/// type is the one whose discriminator prefixes the data. Only the owner's IDL is considered, an
+/// account decoded with the layout of another program would be meaningless.
+pub fn map_account(
+    owner: &Pubkey,
+    data: &[u8],
+    registry: &IdlRegistry,
+    deserializer: &impl ChainparserDeserialize,
+) -> Result<AccountMapResult, ChainparserError> {
+    let (program_idl, idl_source) = registry.get_with_source(owner).ok_or_else(|| {
+        ChainparserError::CannotFindAccountDeserializerForProgramId(owner.to_string())
+    })?;
+    if data.len() < ACCOUNT_DISCRIMINATOR_LEN {
+        return Err(ChainparserError::AccountDataTooShortForDiscriminatorBytes(
+            data.len(),
+            ACCOUNT_DISCRIMINATOR_LEN,
+        ));
+    }
+
+    let (account_name, discriminator) = program_idl
+        .account_discriminators
+        .iter()
+        .find(|(_, discriminator)| !discriminator.is_empty() && data.starts_with(discriminator))
+        .ok_or_else(|| {
+            ChainparserError::UnknownDiscriminatedAccount(hex::encode(
+                &data[..ACCOUNT_DISCRIMINATOR_LEN],
+            ))
+        })?;
+
+    let mut buf = &data[discriminator.len()..];
+    let decoded = deserialize_defined_type(account_name, &program_idl.idl, &mut buf, deserializer)?;
+
+    Ok(AccountMapResult {
+        program_name: program_idl.idl.name.clone(),
+        idl_source,
+        account_name: account_name.clone(),
+        decoded,
+        trailing_bytes: buf.len(),
+    })
+}
@@ -0,0 +1,244 @@
+use colored::Colorize;
+use eyre::eyre;
+use lazy_static::lazy_static;
+use solana_sdk::pubkey::Pubkey;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::{PoisonError, RwLock, RwLockReadGuard};
+
+use super::onchain_idl::{ONCHAIN_CACHE_DIR, cache_path, read_cached_idl};
+use super::program_idl::ProgramIdl;
+
+/// Overrides the directory the IDLs are imported to.
+pub const IDL_DIR_ENV: &str = "SECURE_SQUADS_IDL_DIR";
+
+const SQUADS_PROGRAM_ID: &str = "SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf";
+/// Shipped with the binary, decoding the Squads instructions must not depend on the working directory.
+const SQUADS_IDL: &str = include_str!("../../idl.json");
+
+/// Where a registered IDL came from.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdlSource {
+    Builtin,
+    Imported,
+    /// Read from the Anchor IDL account of the program at `slot`.
+    Onchain {
+        slot: u64,
+    },
+}
+
+impl IdlSource {
+    /// Builtin and imported IDLs were vetted, an on-chain IDL is whatever the upgrade authority of
+    /// the program published next to the code it describes.
+    pub fn is_trusted(&self) -> bool {
+        !matches!(self, IdlSource::Onchain { .. })
+    }
+
+    /// The source with how far to trust it, shown next to every decode.
+    pub fn describe(&self) -> String {
+        if self.is_trusted() {
+            self.to_string()
+        } else {
+            format!("{}, published by the program itself, unverified", self)
+        }
+    }
+}
+
+impl std::fmt::Display for IdlSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdlSource::Builtin => write!(f, "builtin"),
+            IdlSource::Imported => write!(f, "imported"),
+            IdlSource::Onchain { slot } => write!(f, "on-chain at slot {}", slot),
+        }
+    }
+}
+
+/// IDLs keyed by the program they describe, one `<program id>.json` file each in the registry
+/// directory, on-chain IDLs cached in its `onchain` subdirectory.
+#[derive(Default)]
+pub struct IdlRegistry {
+    idls: HashMap<Pubkey, (ProgramIdl, IdlSource)>,
+}
+
+impl IdlRegistry {
+    /// `$SECURE_SQUADS_IDL_DIR`, or `~/.config/secure-squads/idls`.
+    pub fn default_dir() -> PathBuf {
+        if let Some(dir) = std::env::var_os(IDL_DIR_ENV) {
+            return PathBuf::from(dir);
+        }
+        let home = std::env::var_os("HOME").unwrap_or_else(|| ".".into());
+        PathBuf::from(home)
+            .join(".config")
+            .join("secure-squads")
+            .join("idls")
+    }
+
+    /// The builtin Squads IDL, the cached on-chain IDLs and every IDL imported to `dir`, in
+    /// increasing precedence. A file that cannot be read is skipped with a warning, it must not
+    /// take the other IDLs down with it.
+    pub fn load(dir: &Path) -> eyre::Result<Self> {
+        let mut registry = Self::with_squads_idl(Pubkey::from_str(SQUADS_PROGRAM_ID).unwrap());
+
+        for (program_id, path) in idl_files(&dir.join(ONCHAIN_CACHE_DIR))? {
+            match read_cached_idl(&path) {
+                Ok(onchain) => {
+                    let source = IdlSource::Onchain { slot: onchain.slot };
+                    registry.insert(program_id, onchain.idl, source);
+                }
+                Err(e) => warn_skipped(&e),
+            }
+        }
+        for (program_id, path) in idl_files(dir)? {
+            match read_idl(&path) {
+                Ok(idl) => registry.insert(program_id, idl, IdlSource::Imported),
+                Err(e) => warn_skipped(&e),
+            }
+        }
+        Ok(registry)
+    }
+
+    /// A registry decoding only the Squads program deployed at `program_id`.
+    pub fn with_squads_idl(program_id: Pubkey) -> Self {
+        let mut registry = Self::default();
+        registry.insert(program_id, squads_idl(), IdlSource::Builtin);
+        registry
+    }
+
+    pub fn insert(&mut self, program_id: Pubkey, idl: ProgramIdl, source: IdlSource) {
+        self.idls.insert(program_id, (idl, source));
+    }
+
+    pub fn get(&self, program_id: &Pubkey) -> Option<&ProgramIdl> {
+        self.idls.get(program_id).map(|(idl, _)| idl)
+    }
+
+    pub fn get_with_source(&self, program_id: &Pubkey) -> Option<(&ProgramIdl, IdlSource)> {
+        self.idls
+            .get(program_id)
+            .map(|(idl, source)| (idl, *source))
+    }
+
+    /// Registered programs, sorted by program id.
+    pub fn programs(&self) -> Vec<(&Pubkey, &ProgramIdl, IdlSource)> {
+        let mut programs: Vec<_> = self
+            .idls
+            .iter()
+            .map(|(program_id, (idl, source))| (program_id, idl, *source))
+            .collect();
+        programs.sort_by_key(|(program_id, _, _)| **program_id);
+        programs
+    }
+}
+
+fn warn_skipped(e: &eyre::Report) {
+    eprintln!("{}", format!("⚠️ IDL skipped: {}", e).yellow());
+}
+
+/// The `<program id>.json` files of `dir`, a missing directory has none.
+fn idl_files(dir: &Path) -> eyre::Result<Vec<(Pubkey, PathBuf)>> {
+    let entries = match std::fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Vec::new()),
+        Err(e) => return Err(eyre!("Failed to read {}: {}", dir.display(), e)),
+    };
+    let mut files = Vec::new();
+    for entry in entries {
+        let path = entry?.path();
+        let program_id = path
+            .file_stem()
+            .filter(|_| {
+                path.extension()
+                    .is_some_and(|extension| extension == "json")
+            })
+            .and_then(|stem| Pubkey::from_str(&stem.to_string_lossy()).ok());
+        if let Some(program_id) = program_id {
+            files.push((program_id, path));
+        }
+    }
+    Ok(files)
+}
+
+/// The Squads v4 IDL shipped with the binary.
+pub fn squads_idl() -> ProgramIdl {
+    ProgramIdl::parse(SQUADS_IDL).expect("The builtin Squads IDL is invalid")
+}
+
+/// Reads an Anchor (classic or 0.30+) or Shank IDL.
+pub fn read_idl(path: &Path) -> eyre::Result<ProgramIdl> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+    ProgramIdl::parse(&json).map_err(|e| eyre!("{} is not a valid IDL: {}", path.display(), e))
+}
+
+/// Copies the IDL at `path` into `dir` as `<program id>.json`. The program id defaults to the
+/// address the IDL declares.
+pub fn import_idl(
+    dir: &Path,
+    path: &Path,
+    program_id: Option<Pubkey>,
+) -> eyre::Result<(Pubkey, ProgramIdl)> {
+    let idl = read_idl(path)?;
+    let program_id = match (program_id, idl.address) {
+        (Some(program_id), Some(declared)) if program_id != declared => {
+            return Err(eyre!(
+                "The IDL declares the program {}, not {}",
+                declared,
+                program_id
+            ));
+        }
+        (Some(program_id), _) | (None, Some(program_id)) => program_id,
+        (None, None) => {
+            return Err(eyre!(
+                "The IDL does not declare its program address, pass --program-id"
+            ));
+        }
+    };
+
+    std::fs::create_dir_all(dir)?;
+    let target = dir.join(format!("{}.json", program_id));
+    std::fs::copy(path, &target)
+        .map_err(|e| eyre!("Failed to write {}: {}", target.display(), e))?;
+    Ok((program_id, idl))
+}
+
+/// Deletes the imported and the cached on-chain IDL of `program_id`.
+pub fn remove_idl(dir: &Path, program_id: &Pubkey) -> eyre::Result<()> {
+    let mut removed = false;
+    for target in [
+        dir.join(format!("{}.json", program_id)),
+        cache_path(dir, program_id),
+    ] {
+        match std::fs::remove_file(&target) {
+            Ok(()) => removed = true,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(eyre!("Failed to remove {}: {}", target.display(), e)),
+        }
+    }
+    if !removed {
+        return Err(eyre!("No IDL registered for {}", program_id));
+    }
+    Ok(())
+}
+
+lazy_static! {
+    /// The registry every decoder uses, loaded once and extended with the IDLs fetched on the way.
+    static ref IDL_REGISTRY: RwLock<IdlRegistry> = RwLock::new(
+        IdlRegistry::load(&IdlRegistry::default_dir()).unwrap_or_else(|e| {
+            eprintln!("{}", format!("⚠️ IDL registry not loaded: {}", e).yellow());
+            IdlRegistry::with_squads_idl(Pubkey::from_str(SQUADS_PROGRAM_ID).unwrap())
+        })
+    );
+}
+
+pub fn idl_registry() -> RwLockReadGuard<'static, IdlRegistry> {
+    IDL_REGISTRY.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+pub(super) fn register_idl(program_id: Pubkey, idl: ProgramIdl, source: IdlSource) {
+    IDL_REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(program_id, idl, source);
+}
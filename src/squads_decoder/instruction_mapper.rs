@@ -1,5 +1,7 @@
 use super::ParseableInstruction;
 use super::discriminator::discriminator_from_ix;
+use super::idl_registry::{IdlRegistry, IdlSource};
+use super::program_idl::{PdaCheck, flatten_accounts};
 use chainparser::ChainparserDeserialize;
 use chainparser::errors::{ChainparserError, ChainparserResult};
 use lazy_static::lazy_static;
@@ -41,70 +43,136 @@ lazy_static! {
     .collect();
 }
 
+/// Why an instruction could not be decoded with the IDL of its program.
+#[derive(Debug)]
+pub enum DecodeError {
+    /// The data does not start with the full discriminator of the closest IDL instruction, the
+    /// instruction is not that one.
+    DiscriminatorMismatch {
+        instruction: String,
+        expected: Vec<u8>,
+        actual: Vec<u8>,
+    },
+    Chainparser(ChainparserError),
+}
+
+impl std::fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DecodeError::DiscriminatorMismatch {
+                instruction,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Discriminator mismatch: {} expects {}, the data starts with {}",
+                instruction,
+                hex::encode(expected),
+                hex::encode(actual)
+            ),
+            DecodeError::Chainparser(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+impl From<ChainparserError> for DecodeError {
+    fn from(e: ChainparserError) -> Self {
+        DecodeError::Chainparser(e)
+    }
+}
+
+/// How the instruction data was matched to an IDL instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MatchConfidence {
+    /// The data starts with the full Anchor discriminator.
+    Exact,
+    /// Only the first `matched` of the `len` discriminator bytes match, never decoded.
+    Partial { matched: usize, len: usize },
+    /// Shank IDLs identify instructions by a one byte index, any data starting with it matches.
+    ShankIndex,
+}
+
+impl std::fmt::Display for MatchConfidence {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MatchConfidence::Exact => write!(f, "exact"),
+            MatchConfidence::Partial { matched, len } => {
+                write!(f, "partial ({}/{} bytes)", matched, len)
+            }
+            MatchConfidence::ShankIndex => write!(f, "shank index"),
+        }
+    }
+}
+
+/// Decodes `instruction` with the IDL registered for its program id.
 pub fn map_instruction(
     instruction: &impl ParseableInstruction,
-    idl: Option<&Idl>,
+    registry: &IdlRegistry,
     deserializer: &impl ChainparserDeserialize,
-) -> Result<InstructionMapResult, ChainparserError> {
-    //  let program_id = instruction.program_id();
-    let program_name = idl.as_ref().map(|idl| idl.name.to_string());
+) -> Result<InstructionMapResult, DecodeError> {
+    let program_id = instruction.program_id();
+    let (program_idl, idl_source) = registry.get_with_source(program_id).ok_or_else(|| {
+        ChainparserError::CannotFindAccountDeserializerForProgramId(program_id.to_string())
+    })?;
+    let idl = &program_idl.idl;
 
     // Step 1: Find the best matching IDL instruction
 
-    let idl_instruction = idl
-        .as_ref()
-        .and_then(|idl| find_best_matching_idl_ix(&idl.instructions, instruction))
+    let (idl_instruction, confidence) = find_best_matching_idl_ix(&idl.instructions, instruction)
         .ok_or_else(|| {
-            ChainparserError::UnsupportedDeserializer("no matching ix found".to_string())
-        })?;
+        ChainparserError::UnsupportedDeserializer("no matching ix found".to_string())
+    })?;
 
     // Step 2: Decode the instruction data
-    let decoded_args = decode_instruction_data(
-        &idl_instruction,
-        instruction.data(),
-        deserializer,
-        idl.unwrap(),
-    )?;
+    let (decoded_args, trailing_data) =
+        decode_instruction_data(&idl_instruction, instruction.data(), deserializer, idl)?;
     /*
     /// Then it finds the best matching IDL instruction for provided instruction and
     /// creates an entry for each account pubkey providing its name.
     /// */
-    let mapper = idl
-        .as_ref()
-        .and_then(|idl| InstructionMapper::determine_accounts_mapper(instruction, idl));
-    let program_name = idl.as_ref().map(|idl| idl.name.to_string());
-    let program_id = instruction.program_id();
+    let mapper = InstructionMapper::determine_accounts_mapper(instruction, idl);
+    let program_name = idl.name.to_string();
 
     let mut accounts = HashMap::new();
     let mut instruction_name = None::<String>;
     let ix_accounts = instruction.accounts();
     for (idx, pubkey) in ix_accounts.into_iter().enumerate() {
-        if let Some(program_name) = program_name.as_ref() {
-            if &pubkey == program_id {
-                accounts.insert(pubkey, program_name.to_string());
-                continue;
-            }
+        if &pubkey == program_id {
+            accounts.insert(pubkey, program_name.to_string());
+            continue;
         }
         if let Some(mapper) = &mapper {
-            let name = mapper
-                .idl_instruction
-                .accounts
+            let name = flatten_accounts(&mapper.idl_instruction.accounts)
                 .get(idx)
-                .map(|x| x.name().to_string());
+                .map(|x| x.name.to_string());
             if let Some(name) = name {
                 accounts.insert(pubkey, name);
             }
             instruction_name.replace(mapper.idl_instruction.name.to_string());
         }
     }
-    let program_name = idl.map(|x| x.name.to_string());
+
+    // Step 3: Re-derive the accounts the IDL describes as PDAs
+    let pda_checks = program_idl.verify_pdas(
+        program_id,
+        &idl_instruction.name,
+        &instruction.accounts(),
+        &decoded_args,
+    );
 
     // Step 4: Return the result
     Ok(InstructionMapResult {
         accounts,
         instruction_name: Some(idl_instruction.name.clone()),
-        program_name,
+        program_id: *program_id,
+        program_name: Some(program_name),
+        idl_source,
         decoded_args,
+        pda_checks,
+        confidence,
+        trailing_data,
     })
 }
 
@@ -115,8 +183,38 @@ pub struct InstructionMapper {
 pub struct InstructionMapResult {
     pub accounts: HashMap<Pubkey, String>,
     pub instruction_name: Option<String>,
+    pub program_id: Pubkey,
     pub program_name: Option<String>,
+    /// Where the IDL the instruction was decoded with came from.
+    pub idl_source: IdlSource,
     pub decoded_args: serde_json::Value,
+    pub pda_checks: Vec<PdaCheck>,
+    pub confidence: MatchConfidence,
+    /// Bytes left after the IDL arguments, the program reads something the IDL does not describe.
+    pub trailing_data: Vec<u8>,
+}
+
+impl InstructionMapResult {
+    /// What a reviewer should know about how far to trust the decoding.
+    pub fn warnings(&self) -> Vec<String> {
+        let instruction = self.instruction_name.as_deref().unwrap_or("UNKNOWN");
+        let mut warnings = Vec::new();
+        if self.confidence == MatchConfidence::ShankIndex {
+            warnings.push(format!(
+                "{} was matched by its one byte Shank index only",
+                instruction
+            ));
+        }
+        if !self.trailing_data.is_empty() {
+            warnings.push(format!(
+                "{} trailing bytes after the arguments of {} were not decoded: {}",
+                self.trailing_data.len(),
+                instruction,
+                hex::encode(&self.trailing_data)
+            ));
+        }
+        warnings
+    }
 }
 
 impl InstructionMapper {
@@ -125,50 +223,70 @@ impl InstructionMapper {
         idl: &Idl,
     ) -> Option<InstructionMapper> {
         find_best_matching_idl_ix(&idl.instructions, instruction)
-            .map(|idl_instruction| InstructionMapper { idl_instruction })
+            .map(|(idl_instruction, _)| InstructionMapper { idl_instruction })
     }
 }
 
+/// The IDL instruction whose discriminator the data starts with, the longest one when several
+/// match. Without a full match the instruction sharing the longest prefix is returned as a
+/// [`MatchConfidence::Partial`] match, for the mismatch to be reported.
 pub fn find_best_matching_idl_ix(
     ix_idls: &[IdlInstruction],
     ix: &impl ParseableInstruction,
-) -> Option<IdlInstruction> {
+) -> Option<(IdlInstruction, MatchConfidence)> {
     let mut best_match = None;
-    let mut best_match_score = 0;
+    let mut best_match_score = (false, 0);
     for idl_ix in ix_idls {
         let disc = discriminator_from_ix(idl_ix);
         trace!("Discriminator for '{}': {:?}", idl_ix.name, disc);
         if disc.len() > ix.data().len() {
             continue;
         }
-        let mut score = 0;
-        for (a, b) in disc.iter().zip(ix.data()) {
-            if a != b {
-                break;
-            }
-            score += 1;
-        }
-        if score > best_match_score {
-            best_match = Some(idl_ix);
+        let matched = disc
+            .iter()
+            .zip(ix.data())
+            .take_while(|(a, b)| a == b)
+            .count();
+        // Full matches rank above partial ones, whatever their length.
+        let score = (matched == disc.len(), matched);
+        if matched > 0 && score > best_match_score {
+            best_match = Some((idl_ix, disc.len()));
             best_match_score = score;
         }
     }
-    best_match.cloned()
+    best_match.map(|(idl_ix, len)| {
+        let (full, matched) = best_match_score;
+        let is_shank = idl_ix
+            .discriminant
+            .as_ref()
+            .is_some_and(|discriminant| discriminant.bytes.is_none());
+        let confidence = match (full, is_shank) {
+            (false, _) => MatchConfidence::Partial { matched, len },
+            (true, true) => MatchConfidence::ShankIndex,
+            (true, false) => MatchConfidence::Exact,
+        };
+        (idl_ix.clone(), confidence)
+    })
 }
+
+/// Decodes the arguments of `idl_instruction`, returns them with the bytes left after them.
 pub fn decode_instruction_data(
     idl_instruction: &IdlInstruction,
     data: &[u8],
     deserializer: &impl ChainparserDeserialize,
     idl: &Idl,
-) -> ChainparserResult<Value> {
+) -> Result<(Value, Vec<u8>), DecodeError> {
     // Step 1: Extract and verify the discriminator
     let disc = discriminator_from_ix(idl_instruction);
     trace!("Expected Discriminator: {:?}", disc);
-    trace!("Actual Instruction Data: {:?}", &data[..disc.len()]);
+    trace!("Actual Instruction Data: {:?}", data);
 
     if !data.starts_with(&disc) {
-        print!("discriminator mismatch")
-        //return Err(ChainparserError::DiscriminatorMismatch);
+        return Err(DecodeError::DiscriminatorMismatch {
+            instruction: idl_instruction.name.clone(),
+            actual: data[..disc.len().min(data.len())].to_vec(),
+            expected: disc,
+        });
     }
 
     // Step 2: Deserialize the remaining data
@@ -181,8 +299,10 @@ pub fn decode_instruction_data(
         decoded_args.insert(arg.name.clone(), value);
     }
 
-    Ok(Value::Object(decoded_args))
-} // so how to update this then
+    Ok((Value::Object(decoded_args), buf.to_vec()))
+}
+/// Decodes a value of `ty`. Integers of 64 bits and more are emitted as strings, JSON numbers are
+/// doubles in most consumers and would silently round amounts above 2^53.
 fn deserialize_value(
     ty: &IdlType,
     buf: &mut &[u8],
@@ -204,8 +324,16 @@ fn deserialize_value(
             .map_err(|e| e.into()),
         IdlType::U64 => deserializer
             .u64(buf)
-            .map(|v| Value::Number(v.into()))
+            .map(|v| Value::String(v.to_string()))
             .map_err(|e| e.into()),
+        IdlType::U128 => deserializer.u128(buf).map(|v| Value::String(v.to_string())),
+        IdlType::I8 => deserializer.i8(buf).map(|v| Value::Number(v.into())),
+        IdlType::I16 => deserializer.i16(buf).map(|v| Value::Number(v.into())),
+        IdlType::I32 => deserializer.i32(buf).map(|v| Value::Number(v.into())),
+        IdlType::I64 => deserializer.i64(buf).map(|v| Value::String(v.to_string())),
+        IdlType::I128 => deserializer.i128(buf).map(|v| Value::String(v.to_string())),
+        IdlType::F32 => deserializer.f32(buf).map(|v| float_value(v.into())),
+        IdlType::F64 => deserializer.f64(buf).map(float_value),
         IdlType::Bool => deserializer
             .bool(buf)
             .map(|v| Value::Bool(v))
@@ -228,11 +356,38 @@ fn deserialize_value(
                 Ok(Value::Null)
             }
         }
+        // The Solana program `COption`: a u32 tag and the value, whose bytes are present even if
+        // the tag is `None`.
+        IdlType::COption(inner_ty) => match deserializer.u32(buf)? {
+            0 => {
+                let size = fixed_size(inner_ty, idl).ok_or_else(|| {
+                    ChainparserError::DeserializerDoesNotSupportType(
+                        "borsh".to_string(),
+                        format!("coption of variable size {:?}", inner_ty),
+                    )
+                })?;
+                if buf.len() < size {
+                    return Err(ChainparserError::InvalidDataToDeserialize(
+                        "coption".to_string(),
+                        "end of data".to_string(),
+                        buf.to_vec(),
+                    ));
+                }
+                *buf = &buf[size..];
+                Ok(Value::Null)
+            }
+            1 => deserialize_value(inner_ty, buf, deserializer, idl),
+            tag => Err(ChainparserError::InvalidDataToDeserialize(
+                "coption".to_string(),
+                format!("tag {}", tag),
+                buf.to_vec(),
+            )),
+        },
         IdlType::PublicKey => deserializer
             .pubkey(buf)
             .map(|pk| Value::String(pk.to_string()))
             .map_err(|e| e.into()),
-        IdlType::Vec(inner_ty) => {
+        IdlType::Vec(inner_ty) | IdlType::HashSet(inner_ty) | IdlType::BTreeSet(inner_ty) => {
             let len = deserializer.u32(buf)? as usize;
             let mut values = Vec::new();
             for _ in 0..len {
@@ -240,22 +395,86 @@ fn deserialize_value(
             }
             Ok(Value::Array(values))
         }
+        // The length comes from the IDL, which may be hostile: allocate as the elements decode.
+        IdlType::Array(inner_ty, len) => {
+            let mut values = Vec::new();
+            for _ in 0..*len {
+                values.push(deserialize_value(inner_ty, buf, deserializer, idl)?);
+            }
+            Ok(Value::Array(values))
+        }
+        IdlType::Tuple(types) => {
+            let mut values = Vec::with_capacity(types.len());
+            for ty in types {
+                values.push(deserialize_value(ty, buf, deserializer, idl)?);
+            }
+            Ok(Value::Array(values))
+        }
+        // Maps become objects, keys that are not strings are keyed by their JSON text.
+        IdlType::HashMap(key_ty, value_ty) | IdlType::BTreeMap(key_ty, value_ty) => {
+            let len = deserializer.u32(buf)? as usize;
+            let mut entries = Map::new();
+            for _ in 0..len {
+                let key = match deserialize_value(key_ty, buf, deserializer, idl)? {
+                    Value::String(key) => key,
+                    key => key.to_string(),
+                };
+                let value = deserialize_value(value_ty, buf, deserializer, idl)?;
+                entries.insert(key, value);
+            }
+            Ok(Value::Object(entries))
+        }
         IdlType::Defined(type_name) => deserialize_defined_type(type_name, idl, buf, deserializer),
-        _ => Err(ChainparserError::UnsupportedDeserializer(format!(
-            "{:?}",
-            ty
-        ))),
     }
 }
-fn deserialize_defined_type(
+
+/// NaN and infinities have no JSON number, they are kept as text.
+fn float_value(v: f64) -> Value {
+    serde_json::Number::from_f64(v)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(v.to_string()))
+}
+
+/// Serialized size of `ty` when it does not depend on the value, `None` otherwise.
+fn fixed_size(ty: &IdlType, idl: &Idl) -> Option<usize> {
+    match ty {
+        IdlType::Bool | IdlType::U8 | IdlType::I8 => Some(1),
+        IdlType::U16 | IdlType::I16 => Some(2),
+        IdlType::U32 | IdlType::I32 | IdlType::F32 => Some(4),
+        IdlType::U64 | IdlType::I64 | IdlType::F64 => Some(8),
+        IdlType::U128 | IdlType::I128 => Some(16),
+        IdlType::PublicKey => Some(32),
+        IdlType::Array(inner_ty, len) => fixed_size(inner_ty, idl)?.checked_mul(*len),
+        IdlType::Tuple(types) => types
+            .iter()
+            .try_fold(0usize, |total, ty| total.checked_add(fixed_size(ty, idl)?)),
+        IdlType::COption(inner_ty) => fixed_size(inner_ty, idl)?.checked_add(4),
+        IdlType::Defined(type_name) => {
+            let type_def = idl.types.iter().find(|t| &t.name == type_name)?;
+            match &type_def.ty {
+                IdlTypeDefinitionTy::Struct { fields } => {
+                    fields.iter().try_fold(0usize, |total, field| {
+                        total.checked_add(fixed_size(&field.ty, idl)?)
+                    })
+                }
+                IdlTypeDefinitionTy::Enum { .. } => None,
+            }
+        }
+        _ => None,
+    }
+}
+/// Decodes the type or, for account data, the account named `type_name`.
+pub(super) fn deserialize_defined_type(
     type_name: &str,
     idl: &Idl,
     buf: &mut &[u8],
     deserializer: &impl ChainparserDeserialize,
 ) -> ChainparserResult<Value> {
+    // Classic Anchor IDLs define account layouts only in `accounts`.
     let type_def = idl
         .types
         .iter()
+        .chain(&idl.accounts)
         .find(|t| t.name == type_name)
         .ok_or_else(|| ChainparserError::CannotFindDefinedType(type_name.to_string()))?;
 
@@ -285,18 +504,425 @@ fn deserialize_defined_type(
                 .get(discriminant as usize)
                 .ok_or_else(|| ChainparserError::InvalidEnumVariantDiscriminator(discriminant))?;
 
-            let mut decoded_variant = Map::new();
-            if let Some(EnumFields::Named(fields)) = &variant.fields {
-                for field in fields {
-                    let value = deserialize_value(&field.ty, buf, deserializer, idl)?;
-                    decoded_variant.insert(field.name.clone(), value);
+            let decoded_variant = match &variant.fields {
+                Some(EnumFields::Named(fields)) => {
+                    let mut decoded_fields = Map::new();
+                    for field in fields {
+                        let value = deserialize_value(&field.ty, buf, deserializer, idl)?;
+                        decoded_fields.insert(field.name.clone(), value);
+                    }
+                    Value::Object(decoded_fields)
                 }
-            }
+                Some(EnumFields::Tuple(types)) => {
+                    let mut values = Vec::with_capacity(types.len());
+                    for ty in types {
+                        values.push(deserialize_value(ty, buf, deserializer, idl)?);
+                    }
+                    Value::Array(values)
+                }
+                None => Value::Object(Map::new()),
+            };
 
             Ok(Value::Object(Map::from_iter([(
                 variant.name.clone(),
-                Value::Object(decoded_variant),
+                decoded_variant,
             )])))
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::squads_decoder::idl_registry::{IdlSource, squads_idl};
+    use crate::squads_decoder::program_idl::ProgramIdl;
+    use crate::utils::MyInstruction;
+    use chainparser::borsh::BorshDeserializer;
+    use serde_json::json;
+    use squads_multisig::anchor_lang::InstructionData;
+    use squads_multisig::squads_multisig_program::instruction::{
+        ConfigTransactionCreate, SpendingLimitUse,
+    };
+    use squads_multisig::squads_multisig_program::{
+        ConfigTransactionCreateArgs, SpendingLimitUseArgs,
+    };
+    use squads_multisig::state::{ConfigAction, Member, Period, Permissions};
+    use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+    /// Bolt World, a deployed program with an Anchor 0.30 IDL.
+    const BOLT_WORLD_IDL: &str = include_str!("../../tests/fixtures/bolt_world_idl.json");
+
+    /// The types no IDL above uses, in the Anchor 0.30 format.
+    const ANCHOR_TYPES_IDL: &str = r#"{
+        "address": "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+        "metadata": {"name": "types", "version": "0.1.0", "spec": "0.1.0"},
+        "instructions": [{
+            "name": "set",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [],
+            "args": [
+                {"name": "big", "type": "u128"},
+                {"name": "signed", "type": "i128"},
+                {"name": "delta", "type": "i64"},
+                {"name": "ratio", "type": "f64"},
+                {"name": "levels", "type": {"array": ["u16", 3]}},
+                {"name": "pair", "type": {"defined": {"name": "Pair", "generics": [{"kind": "type", "type": "u64"}]}}},
+                {"name": "kinds", "type": {"vec": {"defined": {"name": "Kind"}}}},
+                {"name": "owner", "type": {"option": "pubkey"}}
+            ]
+        }],
+        "accounts": [],
+        "types": [
+            {"name": "Pair", "generics": [{"kind": "type", "name": "T"}], "type": {"kind": "struct", "fields": [
+                {"name": "first", "type": {"generic": "T"}},
+                {"name": "second", "type": {"generic": "T"}}
+            ]}},
+            {"name": "Kind", "type": {"kind": "enum", "variants": [
+                {"name": "Unit"},
+                {"name": "Tuple", "fields": ["u8", "bool"]},
+                {"name": "Named", "fields": [{"name": "x", "type": "i32"}]}
+            ]}}
+        ]
+    }"#;
+
+    /// The types only the classic format has, as Shank emits them.
+    const SHANK_TYPES_IDL: &str = r#"{
+        "version": "0.1.0",
+        "name": "shank_types",
+        "instructions": [{
+            "name": "Configure",
+            "accounts": [],
+            "args": [
+                {"name": "point", "type": {"tuple": ["i16", "u64"]}},
+                {"name": "limits", "type": {"hashMap": ["string", "u32"]}},
+                {"name": "weights", "type": {"bTreeMap": ["u8", "u64"]}},
+                {"name": "tags", "type": {"hashSet": "string"}},
+                {"name": "ids", "type": {"bTreeSet": "u16"}},
+                {"name": "delegate", "type": {"coption": "publicKey"}},
+                {"name": "closeAuthority", "type": {"coption": "publicKey"}},
+                {"name": "tail", "type": "u8"}
+            ],
+            "discriminant": {"type": "u8", "value": 3}
+        }],
+        "metadata": {"origin": "shank"}
+    }"#;
+
+    fn try_decode(idl: ProgramIdl, data: Vec<u8>) -> Result<InstructionMapResult, DecodeError> {
+        let program_id = Pubkey::new_unique();
+        let mut registry = IdlRegistry::default();
+        registry.insert(program_id, idl, IdlSource::Imported);
+        let instruction = MyInstruction {
+            program_id_key: program_id,
+            account_keys: Vec::new(),
+            instruction_data: data,
+        };
+        map_instruction(&instruction, &registry, &BorshDeserializer)
+    }
+
+    fn decode(idl: ProgramIdl, data: Vec<u8>) -> InstructionMapResult {
+        try_decode(idl, data).unwrap()
+    }
+
+    #[test]
+    fn decodes_squads_config_transaction_create() {
+        let member = Pubkey::new_from_array([1; 32]);
+        let create_key = Pubkey::new_from_array([2; 32]);
+        let mint = Pubkey::new_from_array([3; 32]);
+        let data = ConfigTransactionCreate {
+            args: ConfigTransactionCreateArgs {
+                actions: vec![
+                    ConfigAction::AddMember {
+                        new_member: Member {
+                            key: member,
+                            permissions: Permissions { mask: 7 },
+                        },
+                    },
+                    ConfigAction::ChangeThreshold { new_threshold: 2 },
+                    ConfigAction::AddSpendingLimit {
+                        create_key,
+                        vault_index: 1,
+                        mint,
+                        amount: u64::MAX,
+                        period: Period::Week,
+                        members: vec![member],
+                        destinations: vec![],
+                    },
+                    ConfigAction::SetRentCollector {
+                        new_rent_collector: None,
+                    },
+                ],
+                memo: Some("rotate".to_string()),
+            },
+        }
+        .data();
+
+        let decoded = decode(squads_idl(), data);
+        assert_eq!(
+            decoded.instruction_name.as_deref(),
+            Some("configTransactionCreate")
+        );
+        assert_eq!(decoded.confidence, MatchConfidence::Exact);
+        assert!(decoded.trailing_data.is_empty());
+        assert_eq!(
+            decoded.decoded_args,
+            json!({"args": {
+                "actions": [
+                    {"AddMember": {"newMember": {
+                        "key": member.to_string(),
+                        "permissions": {"mask": 7},
+                    }}},
+                    {"ChangeThreshold": {"newThreshold": 2}},
+                    {"AddSpendingLimit": {
+                        "createKey": create_key.to_string(),
+                        "vaultIndex": 1,
+                        "mint": mint.to_string(),
+                        "amount": "18446744073709551615",
+                        "period": {"Week": {}},
+                        "members": [member.to_string()],
+                        "destinations": [],
+                    }},
+                    {"SetRentCollector": {"newRentCollector": null}},
+                ],
+                "memo": "rotate",
+            }})
+        );
+    }
+
+    #[test]
+    fn decodes_squads_spending_limit_use() {
+        let data = SpendingLimitUse {
+            args: SpendingLimitUseArgs {
+                amount: 1_500_000,
+                decimals: 6,
+                memo: None,
+            },
+        }
+        .data();
+
+        let decoded = decode(squads_idl(), data);
+        assert_eq!(
+            decoded.instruction_name.as_deref(),
+            Some("spendingLimitUse")
+        );
+        assert_eq!(
+            decoded.decoded_args,
+            json!({"args": {"amount": "1500000", "decimals": 6, "memo": null}})
+        );
+    }
+
+    #[test]
+    fn decodes_anchor_030_instructions() {
+        let idl = || ProgramIdl::parse(BOLT_WORLD_IDL).unwrap();
+        let add_entity = [163, 241, 57, 35, 244, 244, 48, 57];
+        let apply = [248, 243, 145, 24, 105, 50, 162, 225];
+
+        let mut data = add_entity.to_vec();
+        data.extend(borsh::to_vec(&Some("seed".to_string())).unwrap());
+        let decoded = decode(idl(), data);
+        assert_eq!(decoded.instruction_name.as_deref(), Some("add_entity"));
+        assert_eq!(decoded.confidence, MatchConfidence::Exact);
+        assert_eq!(decoded.decoded_args, json!({"extra_seed": "seed"}));
+
+        let mut data = add_entity.to_vec();
+        data.extend(borsh::to_vec(&None::<String>).unwrap());
+        assert_eq!(
+            decode(idl(), data).decoded_args,
+            json!({"extra_seed": null})
+        );
+
+        let mut data = apply.to_vec();
+        data.extend(borsh::to_vec(&vec![1u8, 2, 3]).unwrap());
+        assert_eq!(decode(idl(), data).decoded_args, json!({"args": [1, 2, 3]}));
+    }
+
+    #[test]
+    fn decodes_anchor_030_types() {
+        let owner = Pubkey::new_from_array([4; 32]);
+        let mut data = vec![1, 2, 3, 4, 5, 6, 7, 8];
+        data.extend(
+            borsh::to_vec(&(
+                u128::MAX,
+                i128::MIN,
+                -5i64,
+                0.5f64,
+                [1u16, 2, 3],
+                (u64::MAX, 1u64),
+            ))
+            .unwrap(),
+        );
+        // Vec<Kind>: Unit, Tuple(9, true), Named { x: -7 }
+        data.extend(3u32.to_le_bytes());
+        data.push(0);
+        data.extend([1, 9, 1]);
+        data.push(2);
+        data.extend((-7i32).to_le_bytes());
+        data.extend(borsh::to_vec(&Some(owner.to_bytes())).unwrap());
+
+        let decoded = decode(ProgramIdl::parse(ANCHOR_TYPES_IDL).unwrap(), data);
+        assert!(decoded.trailing_data.is_empty());
+        assert_eq!(
+            decoded.decoded_args,
+            json!({
+                "big": "340282366920938463463374607431768211455",
+                "signed": "-170141183460469231731687303715884105728",
+                "delta": "-5",
+                "ratio": 0.5,
+                "levels": [1, 2, 3],
+                "pair": {"first": "18446744073709551615", "second": "1"},
+                "kinds": [{"Unit": {}}, {"Tuple": [9, true]}, {"Named": {"x": -7}}],
+                "owner": owner.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn decodes_shank_types() {
+        let delegate = Pubkey::new_from_array([5; 32]);
+        let mut data = vec![3];
+        data.extend(
+            borsh::to_vec(&(
+                (-2i16, 7u64),
+                HashMap::from([("max".to_string(), 10u32)]),
+                BTreeMap::from([(1u8, 5u64), (2, 6)]),
+                HashSet::from(["a".to_string()]),
+                BTreeSet::from([3u16, 1]),
+            ))
+            .unwrap(),
+        );
+        // COption<Pubkey>: a u32 tag, the key is present even when None.
+        data.extend(1u32.to_le_bytes());
+        data.extend(delegate.to_bytes());
+        data.extend(0u32.to_le_bytes());
+        data.extend([0; 32]);
+        data.push(42);
+
+        let decoded = decode(ProgramIdl::parse(SHANK_TYPES_IDL).unwrap(), data);
+        assert_eq!(decoded.confidence, MatchConfidence::ShankIndex);
+        assert!(decoded.trailing_data.is_empty());
+        assert_eq!(
+            decoded.decoded_args,
+            json!({
+                "point": [-2, "7"],
+                "limits": {"max": 10},
+                "weights": {"1": "5", "2": "6"},
+                "tags": ["a"],
+                "ids": [1, 3],
+                "delegate": delegate.to_string(),
+                "closeAuthority": null,
+                "tail": 42,
+            })
+        );
+    }
+
+    #[test]
+    fn rejects_arrays_longer_than_the_data() {
+        for ty in [
+            r#"{"array": ["u8", 18446744073709551615]}"#,
+            r#"{"coption": {"array": ["u64", 18446744073709551615]}}"#,
+        ] {
+            let idl = format!(
+                r#"{{"version": "0.1.0", "name": "hostile", "instructions": [{{
+                    "name": "Drain", "accounts": [], "args": [{{"name": "x", "type": {}}}],
+                    "discriminant": {{"type": "u8", "value": 0}}
+                }}], "metadata": {{"origin": "shank"}}}}"#,
+                ty
+            );
+            let decoded = try_decode(ProgramIdl::parse(&idl).unwrap(), vec![0, 0, 0, 0, 0, 1, 2]);
+            assert!(decoded.is_err());
+        }
+    }
+
+    #[test]
+    fn rejects_partial_discriminator_matches() {
+        let data = SpendingLimitUse {
+            args: SpendingLimitUseArgs {
+                amount: 1,
+                decimals: 0,
+                memo: None,
+            },
+        }
+        .data();
+        let mut partial = data.clone();
+        partial[7] ^= 0xff;
+
+        let idl = squads_idl();
+        let instruction = MyInstruction {
+            program_id_key: Pubkey::new_unique(),
+            account_keys: Vec::new(),
+            instruction_data: partial.clone(),
+        };
+        let (idl_instruction, confidence) =
+            find_best_matching_idl_ix(&idl.idl.instructions, &instruction).unwrap();
+        assert_eq!(idl_instruction.name, "spendingLimitUse");
+        assert_eq!(confidence, MatchConfidence::Partial { matched: 7, len: 8 });
+
+        match try_decode(idl, partial) {
+            Err(DecodeError::DiscriminatorMismatch {
+                instruction,
+                expected,
+                actual,
+            }) => {
+                assert_eq!(instruction, "spendingLimitUse");
+                assert_eq!(expected, data[..8]);
+                assert_eq!(actual[..7], data[..7]);
+                assert_ne!(actual[7], data[7]);
+            }
+            Err(e) => panic!("unexpected error {}", e),
+            Ok(_) => panic!("a partial match was decoded"),
+        }
+    }
+
+    #[test]
+    fn reports_trailing_data() {
+        let mut data = SpendingLimitUse {
+            args: SpendingLimitUseArgs {
+                amount: 1_500_000,
+                decimals: 6,
+                memo: None,
+            },
+        }
+        .data();
+        data.extend([0xde, 0xad]);
+
+        let decoded = decode(squads_idl(), data);
+        assert_eq!(decoded.confidence, MatchConfidence::Exact);
+        assert_eq!(decoded.trailing_data, [0xde, 0xad]);
+        assert_eq!(
+            decoded.warnings(),
+            ["2 trailing bytes after the arguments of spendingLimitUse were not decoded: dead"]
+        );
+        assert_eq!(
+            decoded.decoded_args,
+            json!({"args": {"amount": "1500000", "decimals": 6, "memo": null}})
+        );
+    }
+
+    #[test]
+    fn reports_the_idl_source() {
+        let program_id = Pubkey::new_unique();
+        let mut registry = IdlRegistry::default();
+        registry.insert(program_id, squads_idl(), IdlSource::Onchain { slot: 42 });
+        let instruction = MyInstruction {
+            program_id_key: program_id,
+            account_keys: Vec::new(),
+            instruction_data: SpendingLimitUse {
+                args: SpendingLimitUseArgs {
+                    amount: 1,
+                    decimals: 0,
+                    memo: None,
+                },
+            }
+            .data(),
+        };
+
+        let decoded = map_instruction(&instruction, &registry, &BorshDeserializer).unwrap();
+        assert_eq!(decoded.program_id, program_id);
+        assert_eq!(decoded.idl_source, IdlSource::Onchain { slot: 42 });
+        assert!(!decoded.idl_source.is_trusted());
+        assert_eq!(
+            decoded.idl_source.describe(),
+            "on-chain at slot 42, published by the program itself, unverified"
+        );
+        assert_eq!(IdlSource::Builtin.describe(), "builtin");
+    }
+}
@@ -1,11 +1,21 @@
 use solana_sdk::pubkey::Pubkey;
 
+mod account_mapper;
 mod discriminator;
+mod idl_registry;
 mod instruction_mapper;
+mod onchain_idl;
+mod program_idl;
 pub trait ParseableInstruction {
     fn program_id(&self) -> &Pubkey;
     fn accounts(&self) -> Vec<Pubkey>;
     fn data(&self) -> &[u8];
 }
 
-pub use instruction_mapper::map_instruction;
+pub use account_mapper::map_account;
+pub use idl_registry::{IdlRegistry, IdlSource, idl_registry, import_idl, remove_idl};
+pub use instruction_mapper::{
+    BUILTIN_PROGRAMS, DecodeError, InstructionMapResult, map_instruction,
+};
+pub use onchain_idl::{fetch_idl, load_onchain_idls};
+pub use program_idl::{PdaCheck, PdaStatus};
@@ -0,0 +1,225 @@
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use colored::Colorize;
+use eyre::eyre;
+use flate2::read::ZlibDecoder;
+use serde_json::{Value, json};
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::anchor_lang::Discriminator;
+use squads_multisig::anchor_lang::idl::IdlAccount;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+
+use super::idl_registry::{IdlRegistry, IdlSource, idl_registry, register_idl};
+use super::program_idl::ProgramIdl;
+
+/// Seed `anchor idl init` derives the IDL account with.
+const IDL_SEED: &str = "anchor:idl";
+
+/// Upper bound of an inflated IDL, the largest real ones are a few hundred kilobytes. Anyone
+/// deploying a program writes its IDL account, a zlib bomb must not exhaust the memory.
+const MAX_IDL_LEN: u64 = 16 * 1024 * 1024;
+
+/// Fetched IDLs live next to the imported ones, in this subdirectory of the registry.
+pub const ONCHAIN_CACHE_DIR: &str = "onchain";
+
+/// An Anchor IDL read from the chain.
+pub struct OnchainIdl {
+    pub idl: ProgramIdl,
+    pub idl_address: Pubkey,
+    /// Slot the IDL account was read at.
+    pub slot: u64,
+}
+
+/// The account `anchor idl init` stores the IDL of `program_id` in.
+pub fn idl_address(program_id: &Pubkey) -> Pubkey {
+    let base = Pubkey::find_program_address(&[], program_id).0;
+    Pubkey::create_with_seed(&base, IDL_SEED, program_id).expect("Invalid IDL seed")
+}
+
+/// The IDL JSON of an Anchor `IdlAccount`: discriminator, authority, length prefixed zlib payload.
+/// Anchor declares it `#[account("internal")]`, its discriminator hashes `internal:IdlAccount`.
+pub fn decode_idl_account(data: &[u8]) -> eyre::Result<String> {
+    if data.get(..8) != Some(&IdlAccount::DISCRIMINATOR[..]) {
+        return Err(eyre!("Not an Anchor IDL account"));
+    }
+    let len = data
+        .get(40..44)
+        .map(|len| u32::from_le_bytes(len.try_into().unwrap()) as usize)
+        .ok_or_else(|| eyre!("The IDL account is truncated"))?;
+    let compressed = data
+        .get(44..44 + len)
+        .ok_or_else(|| eyre!("The IDL account is truncated"))?;
+
+    let mut json = String::new();
+    ZlibDecoder::new(compressed)
+        .take(MAX_IDL_LEN)
+        .read_to_string(&mut json)
+        .map_err(|e| eyre!("Failed to decompress the IDL: {}", e))?;
+    if json.len() as u64 >= MAX_IDL_LEN {
+        return Err(eyre!("The IDL inflates to more than {} bytes", MAX_IDL_LEN));
+    }
+    Ok(json)
+}
+
+/// Reads the IDL of `program_id` from the chain and caches it in `dir`, `None` when the program
+/// has no IDL account.
+pub async fn fetch_idl(
+    rpc_client: &RpcClient,
+    dir: &Path,
+    program_id: &Pubkey,
+) -> eyre::Result<Option<OnchainIdl>> {
+    let idl_address = idl_address(program_id);
+    let response = rpc_client
+        .get_account_with_commitment(&idl_address, rpc_client.commitment())
+        .await
+        .map_err(|e| eyre!("Failed to get the IDL account {}: {}", idl_address, e))?;
+    let Some(account) = response.value else {
+        return Ok(None);
+    };
+    if account.owner != *program_id {
+        return Err(eyre!(
+            "SECURITY WARNING: {} is owned by {}, not by the program {}",
+            idl_address,
+            account.owner,
+            program_id
+        ));
+    }
+
+    let json = decode_idl_account(&account.data)?;
+    let idl = ProgramIdl::parse(&json)
+        .map_err(|e| eyre!("The on-chain IDL of {} is invalid: {}", program_id, e))?;
+    let idl_json: Value = serde_json::from_str(&json)?;
+
+    let cached = json!({
+        "program_id": program_id.to_string(),
+        "idl_address": idl_address.to_string(),
+        "slot": response.context.slot,
+        "idl": idl_json,
+    });
+    let path = cache_path(dir, program_id);
+    std::fs::create_dir_all(path.parent().unwrap())?;
+    std::fs::write(&path, serde_json::to_string_pretty(&cached)? + "\n")
+        .map_err(|e| eyre!("Failed to write {}: {}", path.display(), e))?;
+
+    Ok(Some(OnchainIdl {
+        idl,
+        idl_address,
+        slot: response.context.slot,
+    }))
+}
+
+/// Reads an IDL cached by [`fetch_idl`].
+pub fn read_cached_idl(path: &Path) -> eyre::Result<OnchainIdl> {
+    let contents = std::fs::read_to_string(path)
+        .map_err(|e| eyre!("Failed to read {}: {}", path.display(), e))?;
+    let cached: Value = serde_json::from_str(&contents)
+        .map_err(|e| eyre!("{} is not a cached IDL: {}", path.display(), e))?;
+    let invalid = || eyre!("{} is not a cached IDL", path.display());
+
+    let idl_address = cached["idl_address"]
+        .as_str()
+        .and_then(|address| address.parse().ok())
+        .ok_or_else(invalid)?;
+    let slot = cached["slot"].as_u64().ok_or_else(invalid)?;
+    let idl = ProgramIdl::parse(&cached["idl"].to_string())
+        .map_err(|e| eyre!("{} is not a valid IDL: {}", path.display(), e))?;
+    Ok(OnchainIdl {
+        idl,
+        idl_address,
+        slot,
+    })
+}
+
+pub fn cache_path(dir: &Path, program_id: &Pubkey) -> PathBuf {
+    dir.join(ONCHAIN_CACHE_DIR)
+        .join(format!("{}.json", program_id))
+}
+
+/// Fetches the on-chain IDL of every program in `program_ids` the registry has no IDL for yet, so
+/// their instructions decode without importing anything. A program without an IDL account is
+/// skipped, a failed fetch only warns.
+pub async fn load_onchain_idls(rpc_client: &RpcClient, program_ids: &[Pubkey]) {
+    let dir = IdlRegistry::default_dir();
+    for program_id in program_ids {
+        if idl_registry().get(program_id).is_some() {
+            continue;
+        }
+        match fetch_idl(rpc_client, &dir, program_id).await {
+            Ok(Some(onchain)) => {
+                let source = IdlSource::Onchain { slot: onchain.slot };
+                println!(
+                    "{}",
+                    format!(
+                        "⚠️ Loaded the IDL of {} ({}) {}",
+                        program_id,
+                        onchain.idl.idl.name,
+                        source.describe()
+                    )
+                    .yellow()
+                );
+                register_idl(*program_id, onchain.idl, source);
+            }
+            Ok(None) => {}
+            Err(e) => eprintln!(
+                "{}",
+                format!("⚠️ On-chain IDL of {} not loaded: {}", program_id, e).yellow()
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use flate2::Compression;
+    use flate2::write::ZlibEncoder;
+    use std::io::Write;
+
+    /// The Squads v4 IDL in the layout `anchor idl init` writes: discriminator, authority, length
+    /// and zlib payload.
+    const IDL_ACCOUNT: &[u8] = include_bytes!("../../tests/fixtures/squads_idl_account.bin");
+
+    fn idl_account(payload: &[u8]) -> Vec<u8> {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(payload).unwrap();
+        let compressed = encoder.finish().unwrap();
+
+        let mut data = IdlAccount::DISCRIMINATOR.to_vec();
+        data.extend_from_slice(Pubkey::new_unique().as_ref());
+        data.extend_from_slice(&(compressed.len() as u32).to_le_bytes());
+        data.extend_from_slice(&compressed);
+        data
+    }
+
+    #[test]
+    fn decodes_the_idl_account() {
+        assert_eq!(
+            IDL_ACCOUNT[..8],
+            [24, 70, 98, 191, 58, 144, 123, 158],
+            "IdlAccount discriminator"
+        );
+        let json = decode_idl_account(IDL_ACCOUNT).unwrap();
+        let idl = ProgramIdl::parse(&json).unwrap();
+        assert_eq!(idl.idl.name, "squads_multisig_program");
+        assert_eq!(
+            serde_json::from_str::<Value>(&json).unwrap(),
+            serde_json::from_str::<Value>(include_str!("../../idl.json")).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejects_other_accounts() {
+        let mut data = IDL_ACCOUNT.to_vec();
+        data[..8].copy_from_slice(&solana_sdk::hash::hash(b"account:IdlAccount").to_bytes()[..8]);
+        assert!(decode_idl_account(&data).is_err());
+        assert!(decode_idl_account(&IDL_ACCOUNT[..40]).is_err());
+    }
+
+    #[test]
+    fn rejects_zlib_bombs() {
+        let data = idl_account(&vec![b' '; MAX_IDL_LEN as usize]);
+        let e = decode_idl_account(&data).unwrap_err();
+        assert!(e.to_string().contains("inflates to more than"), "{}", e);
+    }
+}
@@ -0,0 +1,575 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use anchor_lang_idl_spec::{
+    Idl as SpecIdl, IdlArrayLen, IdlDefinedFields, IdlGenericArg, IdlInstructionAccountItem,
+    IdlPda, IdlSeed, IdlType as SpecIdlType, IdlTypeDef, IdlTypeDefGeneric, IdlTypeDefTy,
+};
+use eyre::eyre;
+use serde_json::Value;
+use solana_idl::{
+    Idl, IdlAccount, IdlAccountItem, IdlField, IdlInstructionDiscriminant, IdlType,
+    IdlTypeDefinitionTy,
+};
+use solana_sdk::hash::hash;
+use solana_sdk::pubkey::Pubkey;
+
+/// Classic Anchor, Shank and Anchor 0.30+ IDLs in one model: the classic layout the decoder walks,
+/// plus what the classic format cannot express.
+#[derive(Clone, Debug)]
+pub struct ProgramIdl {
+    pub idl: Idl,
+    /// Program address the IDL declares.
+    pub address: Option<Pubkey>,
+    /// Discriminator of every account type, by account name.
+    pub account_discriminators: Vec<(String, Vec<u8>)>,
+    /// PDA seeds of the accounts of every instruction, by instruction name, in account order.
+    pub pdas: HashMap<String, Vec<Option<IdlPda>>>,
+}
+
+/// Outcome of re-deriving an instruction account from the PDA seeds in the IDL.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PdaStatus {
+    Verified,
+    Mismatch {
+        expected: Pubkey,
+    },
+    /// The seeds depend on something the instruction does not contain, e.g. account data.
+    Unverifiable(String),
+}
+
+#[derive(Clone, Debug)]
+pub struct PdaCheck {
+    pub account: String,
+    pub pubkey: Pubkey,
+    pub status: PdaStatus,
+}
+
+impl ProgramIdl {
+    /// Reads a classic Anchor, Shank or Anchor 0.30+ IDL.
+    pub fn parse(json: &str) -> eyre::Result<Self> {
+        let value: Value = serde_json::from_str(json)?;
+        if value["address"].is_string() && value["metadata"]["spec"].is_string() {
+            let spec: SpecIdl =
+                serde_json::from_value(value).map_err(|e| eyre!("Invalid Anchor IDL: {}", e))?;
+            return Self::from_spec(spec);
+        }
+        let idl = solana_idl::try_extract_classic_idl(json).map_err(|e| eyre!("{}", e))?;
+        Ok(Self::from_classic(idl))
+    }
+
+    fn from_classic(idl: Idl) -> Self {
+        let address = idl
+            .metadata
+            .as_ref()
+            .and_then(|metadata| metadata.address.as_deref())
+            .and_then(|address| Pubkey::from_str(address).ok());
+        let is_shank = idl
+            .metadata
+            .as_ref()
+            .is_some_and(|metadata| metadata.origin.as_deref() == Some("shank"));
+        // Shank accounts carry no discriminator, Anchor derives it from the account name.
+        let account_discriminators = if is_shank {
+            Vec::new()
+        } else {
+            idl.accounts
+                .iter()
+                .map(|account| {
+                    let preimage = format!("account:{}", account.name);
+                    (
+                        account.name.clone(),
+                        hash(preimage.as_bytes()).to_bytes()[..8].to_vec(),
+                    )
+                })
+                .collect()
+        };
+        Self {
+            idl,
+            address,
+            account_discriminators,
+            pdas: HashMap::new(),
+        }
+    }
+
+    fn from_spec(mut spec: SpecIdl) -> eyre::Result<Self> {
+        let address = Pubkey::from_str(&spec.address)
+            .map_err(|_| eyre!("Invalid program address {} in the IDL", spec.address))?;
+        monomorphize(&mut spec)?;
+
+        let account_discriminators = spec
+            .accounts
+            .iter()
+            .map(|account| (account.name.clone(), account.discriminator.clone()))
+            .collect();
+        let pdas = spec
+            .instructions
+            .iter()
+            .map(|ix| {
+                let mut pdas = Vec::new();
+                flatten_spec_pdas(&ix.accounts, &mut pdas);
+                (ix.name.clone(), pdas)
+            })
+            .collect();
+        let discriminators: Vec<Vec<u8>> = spec
+            .instructions
+            .iter()
+            .map(|ix| ix.discriminator.clone())
+            .collect();
+
+        let mut idl = solana_idl_converter::anchor_to_classic::try_convert(spec)
+            .map_err(|e| eyre!("{}", e))?;
+        // The conversion drops empty discriminators, which would fall back to the name sighash.
+        for (ix, discriminator) in idl.instructions.iter_mut().zip(discriminators) {
+            ix.discriminant = Some(IdlInstructionDiscriminant {
+                ty: IdlType::U8,
+                value: discriminator.first().copied().unwrap_or_default(),
+                bytes: Some(discriminator),
+            });
+        }
+
+        Ok(Self {
+            idl,
+            address: Some(address),
+            account_discriminators,
+            pdas,
+        })
+    }
+
+    /// Re-derives every account of `instruction_name` the IDL describes as a PDA and compares it
+    /// with the account passed.
+    pub fn verify_pdas(
+        &self,
+        program_id: &Pubkey,
+        instruction_name: &str,
+        accounts: &[Pubkey],
+        args: &Value,
+    ) -> Vec<PdaCheck> {
+        let (Some(pdas), Some(ix)) = (
+            self.pdas.get(instruction_name),
+            self.idl
+                .instructions
+                .iter()
+                .find(|ix| ix.name == instruction_name),
+        ) else {
+            return Vec::new();
+        };
+        let names: Vec<&str> = flatten_accounts(&ix.accounts)
+            .iter()
+            .map(|account| account.name.as_str())
+            .collect();
+        let account_by_path = |path: &str| {
+            names
+                .iter()
+                .position(|name| *name == path)
+                .and_then(|position| accounts.get(position))
+        };
+
+        let mut checks = Vec::new();
+        for (position, pda) in pdas.iter().enumerate() {
+            let (Some(pda), Some(pubkey)) = (pda, accounts.get(position)) else {
+                continue;
+            };
+            let seed_bytes = |seed: &IdlSeed| -> Result<Vec<u8>, String> {
+                match seed {
+                    IdlSeed::Const(seed) => Ok(seed.value.clone()),
+                    IdlSeed::Account(seed) => account_by_path(&seed.path)
+                        .map(|account| account.to_bytes().to_vec())
+                        .ok_or_else(|| format!("seed reads account data at {}", seed.path)),
+                    IdlSeed::Arg(seed) => arg_seed(&self.idl, &ix.args, args, &seed.path)
+                        .ok_or_else(|| format!("unsupported argument seed {}", seed.path)),
+                }
+            };
+
+            let derived = pda
+                .seeds
+                .iter()
+                .map(seed_bytes)
+                .collect::<Result<Vec<_>, _>>()
+                .and_then(|seeds| {
+                    let program = match &pda.program {
+                        Some(program) => seed_bytes(program).and_then(|bytes| {
+                            Pubkey::try_from(bytes.as_slice())
+                                .map_err(|_| "invalid PDA program seed".to_string())
+                        })?,
+                        None => *program_id,
+                    };
+                    let seeds: Vec<&[u8]> = seeds.iter().map(Vec::as_slice).collect();
+                    Ok(Pubkey::find_program_address(&seeds, &program).0)
+                });
+            let status = match derived {
+                Ok(expected) if expected == *pubkey => PdaStatus::Verified,
+                Ok(expected) => PdaStatus::Mismatch { expected },
+                Err(reason) => PdaStatus::Unverifiable(reason),
+            };
+            checks.push(PdaCheck {
+                account: names.get(position).unwrap_or(&"?").to_string(),
+                pubkey: *pubkey,
+                status,
+            });
+        }
+        checks
+    }
+}
+
+/// Accounts of an instruction in the order they are passed, composite accounts inlined.
+pub fn flatten_accounts(items: &[IdlAccountItem]) -> Vec<&IdlAccount> {
+    let mut accounts = Vec::new();
+    for item in items {
+        match item {
+            IdlAccountItem::IdlAccount(account) => accounts.push(account),
+            IdlAccountItem::IdlAccounts(composite) => {
+                accounts.extend(flatten_accounts(&composite.accounts))
+            }
+        }
+    }
+    accounts
+}
+
+fn flatten_spec_pdas(items: &[IdlInstructionAccountItem], pdas: &mut Vec<Option<IdlPda>>) {
+    for item in items {
+        match item {
+            IdlInstructionAccountItem::Single(account) => pdas.push(account.pda.clone()),
+            IdlInstructionAccountItem::Composite(composite) => {
+                flatten_spec_pdas(&composite.accounts, pdas)
+            }
+        }
+    }
+}
+
+/// The seed bytes of the decoded argument at `path`, e.g. `params.index`.
+fn arg_seed(idl: &Idl, fields: &[IdlField], args: &Value, path: &str) -> Option<Vec<u8>> {
+    let mut segments = path.split('.');
+    let first = segments.next()?;
+    let mut ty = &fields.iter().find(|field| field.name == first)?.ty;
+    let mut value = args.get(first)?;
+    for segment in segments {
+        let IdlType::Defined(type_name) = ty else {
+            return None;
+        };
+        let IdlTypeDefinitionTy::Struct { fields } =
+            &idl.types.iter().find(|t| &t.name == type_name)?.ty
+        else {
+            return None;
+        };
+        ty = &fields.iter().find(|field| field.name == segment)?.ty;
+        value = value.get(segment)?;
+    }
+    seed_value_bytes(ty, value)
+}
+
+/// Bytes Anchor seeds a value with: little endian integers, raw pubkeys, strings and bytes.
+fn seed_value_bytes(ty: &IdlType, value: &Value) -> Option<Vec<u8>> {
+    let unsigned = || {
+        value
+            .as_u64()
+            .map(u128::from)
+            .or_else(|| value.as_str()?.parse().ok())
+    };
+    let signed = || {
+        value
+            .as_i64()
+            .map(i128::from)
+            .or_else(|| value.as_str()?.parse().ok())
+    };
+    let bytes = match ty {
+        IdlType::Bool => vec![u8::from(value.as_bool()?)],
+        IdlType::U8 => u8::try_from(unsigned()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::U16 => u16::try_from(unsigned()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::U32 => u32::try_from(unsigned()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::U64 => u64::try_from(unsigned()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::U128 => unsigned()?.to_le_bytes().to_vec(),
+        IdlType::I8 => i8::try_from(signed()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::I16 => i16::try_from(signed()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::I32 => i32::try_from(signed()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::I64 => i64::try_from(signed()?).ok()?.to_le_bytes().to_vec(),
+        IdlType::I128 => signed()?.to_le_bytes().to_vec(),
+        IdlType::PublicKey => Pubkey::from_str(value.as_str()?).ok()?.to_bytes().to_vec(),
+        IdlType::String => value.as_str()?.as_bytes().to_vec(),
+        IdlType::Bytes => byte_array(value)?,
+        IdlType::Vec(inner) | IdlType::Array(inner, _) if **inner == IdlType::U8 => {
+            byte_array(value)?
+        }
+        _ => return None,
+    };
+    Some(bytes)
+}
+
+fn byte_array(value: &Value) -> Option<Vec<u8>> {
+    value
+        .as_array()?
+        .iter()
+        .map(|byte| u8::try_from(byte.as_u64()?).ok())
+        .collect()
+}
+
+/// Replaces generic types by one concrete type per instantiation, e.g. `Pair<u64>`, and inlines
+/// type aliases, neither exists in the classic format.
+fn monomorphize(spec: &mut SpecIdl) -> eyre::Result<()> {
+    let defs = std::mem::take(&mut spec.types);
+    let mut types = Vec::new();
+    let no_generics = HashMap::new();
+    for def in &defs {
+        if !def.generics.is_empty() || matches!(def.ty, IdlTypeDefTy::Type { .. }) {
+            continue;
+        }
+        let ty = resolve_def_ty(&def.ty, &defs, &no_generics, &mut types)?;
+        types.push(IdlTypeDef { ty, ..def.clone() });
+    }
+    for ix in &mut spec.instructions {
+        for arg in &mut ix.args {
+            arg.ty = resolve_type(&arg.ty, &defs, &no_generics, &mut types)?;
+        }
+    }
+    spec.types = types;
+    Ok(())
+}
+
+fn resolve_def_ty(
+    ty: &IdlTypeDefTy,
+    defs: &[IdlTypeDef],
+    generics: &HashMap<String, IdlGenericArg>,
+    types: &mut Vec<IdlTypeDef>,
+) -> eyre::Result<IdlTypeDefTy> {
+    let mut resolve_fields = |fields: &Option<IdlDefinedFields>| -> eyre::Result<_> {
+        Ok(match fields {
+            Some(IdlDefinedFields::Named(fields)) => Some(IdlDefinedFields::Named(
+                fields
+                    .iter()
+                    .map(|field| {
+                        let ty = resolve_type(&field.ty, defs, generics, types)?;
+                        Ok(anchor_lang_idl_spec::IdlField {
+                            ty,
+                            ..field.clone()
+                        })
+                    })
+                    .collect::<eyre::Result<_>>()?,
+            )),
+            Some(IdlDefinedFields::Tuple(tys)) => Some(IdlDefinedFields::Tuple(
+                tys.iter()
+                    .map(|ty| resolve_type(ty, defs, generics, types))
+                    .collect::<eyre::Result<_>>()?,
+            )),
+            None => None,
+        })
+    };
+    Ok(match ty {
+        IdlTypeDefTy::Struct { fields } => IdlTypeDefTy::Struct {
+            fields: resolve_fields(fields)?,
+        },
+        IdlTypeDefTy::Enum { variants } => IdlTypeDefTy::Enum {
+            variants: variants
+                .iter()
+                .map(|variant| {
+                    let mut variant = variant.clone();
+                    variant.fields = resolve_fields(&variant.fields)?;
+                    Ok(variant)
+                })
+                .collect::<eyre::Result<_>>()?,
+        },
+        IdlTypeDefTy::Type { alias } => IdlTypeDefTy::Type {
+            alias: resolve_type(alias, defs, generics, types)?,
+        },
+    })
+}
+
+fn resolve_type(
+    ty: &SpecIdlType,
+    defs: &[IdlTypeDef],
+    generics: &HashMap<String, IdlGenericArg>,
+    types: &mut Vec<IdlTypeDef>,
+) -> eyre::Result<SpecIdlType> {
+    Ok(match ty {
+        SpecIdlType::Generic(name) => match generics.get(name) {
+            Some(IdlGenericArg::Type { ty }) => ty.clone(),
+            _ => return Err(eyre!("Unbound generic type {}", name)),
+        },
+        SpecIdlType::Option(inner) => {
+            SpecIdlType::Option(Box::new(resolve_type(inner, defs, generics, types)?))
+        }
+        SpecIdlType::Vec(inner) => {
+            SpecIdlType::Vec(Box::new(resolve_type(inner, defs, generics, types)?))
+        }
+        SpecIdlType::Array(inner, len) => {
+            let len = match len {
+                IdlArrayLen::Generic(name) => match generics.get(name) {
+                    Some(IdlGenericArg::Const { value }) => IdlArrayLen::Value(
+                        value
+                            .parse()
+                            .map_err(|_| eyre!("Invalid array length {}", value))?,
+                    ),
+                    _ => return Err(eyre!("Unbound generic length {}", name)),
+                },
+                IdlArrayLen::Value(len) => IdlArrayLen::Value(*len),
+            };
+            SpecIdlType::Array(Box::new(resolve_type(inner, defs, generics, types)?), len)
+        }
+        SpecIdlType::Defined {
+            name,
+            generics: args,
+        } => {
+            let args = args
+                .iter()
+                .map(|arg| {
+                    Ok(match arg {
+                        IdlGenericArg::Type { ty } => IdlGenericArg::Type {
+                            ty: resolve_type(ty, defs, generics, types)?,
+                        },
+                        // A const argument may forward a const generic of the enclosing type.
+                        IdlGenericArg::Const { value } => match generics.get(value) {
+                            Some(arg @ IdlGenericArg::Const { .. }) => arg.clone(),
+                            _ => arg.clone(),
+                        },
+                    })
+                })
+                .collect::<eyre::Result<Vec<_>>>()?;
+            let Some(def) = defs.iter().find(|def| &def.name == name) else {
+                return Ok(SpecIdlType::Defined {
+                    name: name.clone(),
+                    generics: args,
+                });
+            };
+            let bound: HashMap<String, IdlGenericArg> =
+                def.generics
+                    .iter()
+                    .map(|generic| match generic {
+                        IdlTypeDefGeneric::Type { name }
+                        | IdlTypeDefGeneric::Const { name, .. } => name.clone(),
+                    })
+                    .zip(args.iter().cloned())
+                    .collect();
+            if let IdlTypeDefTy::Type { alias } = &def.ty {
+                return resolve_type(alias, defs, &bound, types);
+            }
+            if def.generics.is_empty() {
+                return Ok(SpecIdlType::Defined {
+                    name: name.clone(),
+                    generics: Vec::new(),
+                });
+            }
+
+            let instance = format!(
+                "{}<{}>",
+                name,
+                args.iter()
+                    .map(generic_arg_name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+            if !types.iter().any(|def| def.name == instance) {
+                // Registered before resolving the fields, a recursive type refers to itself.
+                types.push(IdlTypeDef {
+                    name: instance.clone(),
+                    generics: Vec::new(),
+                    ..def.clone()
+                });
+                let ty = resolve_def_ty(&def.ty, defs, &bound, types)?;
+                if let Some(def) = types.iter_mut().find(|def| def.name == instance) {
+                    def.ty = ty;
+                }
+            }
+            SpecIdlType::Defined {
+                name: instance,
+                generics: Vec::new(),
+            }
+        }
+        ty => ty.clone(),
+    })
+}
+
+fn generic_arg_name(arg: &IdlGenericArg) -> String {
+    match arg {
+        IdlGenericArg::Type {
+            ty: SpecIdlType::Defined { name, .. },
+        } => name.clone(),
+        IdlGenericArg::Type { ty } => serde_json::to_value(ty)
+            .ok()
+            .and_then(|ty| ty.as_str().map(str::to_string))
+            .unwrap_or_else(|| format!("{:?}", ty)),
+        IdlGenericArg::Const { value } => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const VAULT_IDL: &str = r#"{
+        "address": "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS",
+        "metadata": {"name": "vaults", "version": "0.1.0", "spec": "0.1.0"},
+        "instructions": [{
+            "name": "deposit",
+            "discriminator": [1, 2, 3, 4, 5, 6, 7, 8],
+            "accounts": [
+                {"name": "authority", "signer": true},
+                {"name": "vault", "writable": true, "pda": {"seeds": [
+                    {"kind": "const", "value": [118, 97, 117, 108, 116]},
+                    {"kind": "account", "path": "authority"},
+                    {"kind": "arg", "path": "params.id"}
+                ]}},
+                {"name": "receipt", "writable": true, "pda": {"seeds": [
+                    {"kind": "const", "value": [114, 101, 99, 101, 105, 112, 116]},
+                    {"kind": "account", "path": "vault.owner", "account": "Vault"}
+                ]}}
+            ],
+            "args": [{"name": "params", "type": {"defined": {"name": "DepositParams"}}}]
+        }],
+        "accounts": [{"name": "Vault", "discriminator": [9, 9, 9, 9, 9, 9, 9, 9]}],
+        "types": [
+            {"name": "DepositParams", "type": {"kind": "struct", "fields": [
+                {"name": "id", "type": "u64"},
+                {"name": "amount", "type": "u64"}
+            ]}},
+            {"name": "Vault", "type": {"kind": "struct", "fields": [{"name": "owner", "type": "pubkey"}]}}
+        ]
+    }"#;
+
+    fn deposit_checks(vault: Option<Pubkey>) -> (Pubkey, Vec<PdaCheck>) {
+        let idl = ProgramIdl::parse(VAULT_IDL).unwrap();
+        let program_id = idl.address.unwrap();
+        let authority = Pubkey::new_from_array([1; 32]);
+        let expected = Pubkey::find_program_address(
+            &[b"vault", authority.as_ref(), &7u64.to_le_bytes()],
+            &program_id,
+        )
+        .0;
+        let accounts = [
+            authority,
+            vault.unwrap_or(expected),
+            Pubkey::new_from_array([3; 32]),
+        ];
+        // As the decoder emits them, u64 as strings.
+        let args = json!({"params": {"id": "7", "amount": "100"}});
+        (
+            expected,
+            idl.verify_pdas(&program_id, "deposit", &accounts, &args),
+        )
+    }
+
+    #[test]
+    fn verifies_pda_seeds() {
+        let (expected, checks) = deposit_checks(None);
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].account, "vault");
+        assert_eq!(checks[0].pubkey, expected);
+        assert_eq!(checks[0].status, PdaStatus::Verified);
+    }
+
+    #[test]
+    fn reports_mismatched_pdas() {
+        let wrong = Pubkey::new_from_array([2; 32]);
+        let (expected, checks) = deposit_checks(Some(wrong));
+        assert_eq!(checks[0].pubkey, wrong);
+        assert_eq!(checks[0].status, PdaStatus::Mismatch { expected });
+    }
+
+    #[test]
+    fn reports_account_data_seeds_as_unverifiable() {
+        let (_, checks) = deposit_checks(None);
+        assert_eq!(checks[1].account, "receipt");
+        assert_eq!(
+            checks[1].status,
+            PdaStatus::Unverifiable("seed reads account data at vault.owner".to_string())
+        );
+    }
+}
@@ -0,0 +1,302 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+use std::sync::{PoisonError, RwLock};
+
+use eyre::eyre;
+use lazy_static::lazy_static;
+use solana_program::program_pack::Pack;
+use solana_sdk::instruction::Instruction;
+use solana_sdk::pubkey::Pubkey;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+
+pub const TOKEN_2022_PROGRAM_ID: &str = "TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb";
+
+/// Token-2022 TLV extensions start after the account type byte following the padded base account.
+const TOKEN_2022_EXTENSIONS_OFFSET: usize = spl_token::state::Account::LEN + 1;
+const TOKEN_METADATA_EXTENSION_TYPE: u16 = 19;
+
+/// What is needed to turn base units of a mint into a human readable amount.
+#[derive(Clone, Debug)]
+pub struct MintInfo {
+    pub mint: Pubkey,
+    pub token_program: Pubkey,
+    pub decimals: u8,
+    pub symbol: Option<String>,
+    pub name: Option<String>,
+}
+
+impl MintInfo {
+    /// Native SOL, spending limits use the default pubkey as its mint.
+    pub fn sol() -> Self {
+        Self {
+            mint: Pubkey::default(),
+            token_program: solana_sdk::system_program::id(),
+            decimals: 9,
+            symbol: Some("SOL".to_string()),
+            name: Some("Solana".to_string()),
+        }
+    }
+
+    /// `12.5 USDC (12500000 base units)`, the mint stands in for a missing symbol.
+    pub fn display(&self, amount: u64) -> String {
+        let symbol = self.symbol.clone().unwrap_or_else(|| self.mint.to_string());
+        format!(
+            "{} {} ({} base units)",
+            format_ui_amount(amount, self.decimals),
+            symbol,
+            amount
+        )
+    }
+}
+
+/// `transfer_checked` for both token programs, Token-2022 keeps the SPL Token instruction layout.
+pub fn transfer_checked_instruction(
+    mint_info: &MintInfo,
+    source: &Pubkey,
+    destination: &Pubkey,
+    authority: &Pubkey,
+    amount: u64,
+) -> Instruction {
+    let mut instruction = spl_token::instruction::transfer_checked(
+        &spl_token::id(),
+        source,
+        &mint_info.mint,
+        destination,
+        authority,
+        &[],
+        amount,
+        mint_info.decimals,
+    )
+    .expect("Failed to build transfer_checked instruction");
+    instruction.program_id = mint_info.token_program;
+    instruction
+}
+
+lazy_static! {
+    /// Mints fetched so far, so code without an RPC client can still format amounts.
+    static ref MINT_INFOS: RwLock<HashMap<Pubkey, MintInfo>> = RwLock::new(HashMap::new());
+}
+
+/// The mint info [`fetch_mint_info`] loaded earlier, if any.
+pub fn cached_mint_info(mint: &Pubkey) -> Option<MintInfo> {
+    if *mint == Pubkey::default() {
+        return Some(MintInfo::sol());
+    }
+    MINT_INFOS
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(mint)
+        .cloned()
+}
+
+/// Loads the decimals of a SPL Token or Token-2022 mint, and the Token-2022 metadata if present.
+pub async fn fetch_mint_info(rpc_client: &RpcClient, mint: &Pubkey) -> eyre::Result<MintInfo> {
+    if let Some(mint_info) = cached_mint_info(mint) {
+        return Ok(mint_info);
+    }
+    let mint_info = load_mint_info(rpc_client, mint).await?;
+    MINT_INFOS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(*mint, mint_info.clone());
+    Ok(mint_info)
+}
+
+async fn load_mint_info(rpc_client: &RpcClient, mint: &Pubkey) -> eyre::Result<MintInfo> {
+    let account = rpc_client
+        .get_account(mint)
+        .await
+        .map_err(|e| eyre!("Failed to get mint account {}: {}", mint, e))?;
+    let token_2022 = Pubkey::from_str(TOKEN_2022_PROGRAM_ID).unwrap();
+    if account.owner != spl_token::id() && account.owner != token_2022 {
+        return Err(eyre!(
+            "{} is owned by {}, not by a token program",
+            mint,
+            account.owner
+        ));
+    }
+    // Token-2022 mints share the base layout, extensions follow after it.
+    let base = account
+        .data
+        .get(..spl_token::state::Mint::LEN)
+        .ok_or_else(|| eyre!("{} is not a mint account", mint))?;
+    let decimals = spl_token::state::Mint::unpack_from_slice(base)
+        .map_err(|_| eyre!("{} is not a mint account", mint))?
+        .decimals;
+
+    let (name, symbol) = match token_metadata(&account.data) {
+        Some((name, symbol)) => (Some(name), Some(symbol)),
+        None if *mint == spl_token::native_mint::id() => {
+            (Some("Wrapped SOL".to_string()), Some("wSOL".to_string()))
+        }
+        None => (None, None),
+    };
+
+    Ok(MintInfo {
+        mint: *mint,
+        token_program: account.owner,
+        decimals,
+        symbol,
+        name,
+    })
+}
+
+/// Name and symbol of the Token-2022 `TokenMetadata` extension.
+fn token_metadata(data: &[u8]) -> Option<(String, String)> {
+    let mut tlv = data.get(TOKEN_2022_EXTENSIONS_OFFSET..)?;
+    while tlv.len() >= 4 {
+        let extension_type = u16::from_le_bytes([tlv[0], tlv[1]]);
+        let length = usize::from(u16::from_le_bytes([tlv[2], tlv[3]]));
+        let value = tlv.get(4..4 + length)?;
+        if extension_type == TOKEN_METADATA_EXTENSION_TYPE {
+            // update_authority and mint come before the borsh strings.
+            let mut rest = value.get(64..)?;
+            let name = read_borsh_string(&mut rest)?;
+            let symbol = read_borsh_string(&mut rest)?;
+            return Some((name, symbol));
+        }
+        tlv = &tlv[4 + length..];
+    }
+    None
+}
+
+fn read_borsh_string(data: &mut &[u8]) -> Option<String> {
+    let length = u32::from_le_bytes(data.get(..4)?.try_into().ok()?) as usize;
+    let value = String::from_utf8(data.get(4..4 + length)?.to_vec()).ok()?;
+    *data = &data[4 + length..];
+    Some(value)
+}
+
+/// Parses `12.5` into base units without going through floats.
+pub fn parse_ui_amount(amount: &str, decimals: u8) -> eyre::Result<u64> {
+    let invalid = || eyre!("Invalid amount '{}'", amount);
+    let (whole, fraction) = amount.trim().split_once('.').unwrap_or((amount.trim(), ""));
+    if whole.is_empty() && fraction.is_empty()
+        || !whole.chars().all(|c| c.is_ascii_digit())
+        || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(invalid());
+    }
+    if fraction.len() > usize::from(decimals) {
+        return Err(eyre!(
+            "Amount '{}' has more than the {} decimals of the mint",
+            amount,
+            decimals
+        ));
+    }
+    let digits = format!(
+        "{}{:0<width$}",
+        whole,
+        fraction,
+        width = usize::from(decimals)
+    );
+    let raw = digits.trim_start_matches('0');
+    if raw.is_empty() {
+        return Err(eyre!("Amount must be greater than zero"));
+    }
+    raw.parse::<u64>()
+        .map_err(|_| eyre!("Amount '{}' does not fit into a u64", amount))
+}
+
+/// Formats base units with the mint decimals, trailing zeros trimmed: `12500000` → `12.5`.
+pub fn format_ui_amount(amount: u64, decimals: u8) -> String {
+    let decimals = usize::from(decimals);
+    if decimals == 0 {
+        return amount.to_string();
+    }
+    let digits = format!("{:0>width$}", amount, width = decimals + 1);
+    let (whole, fraction) = digits.split_at(digits.len() - decimals);
+    let fraction = fraction.trim_end_matches('0');
+    if fraction.is_empty() {
+        whole.to_string()
+    } else {
+        format!("{}.{}", whole, fraction)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_ui_amounts() {
+        assert_eq!(parse_ui_amount("12.5", 6).unwrap(), 12_500_000);
+        assert_eq!(parse_ui_amount(".5", 6).unwrap(), 500_000);
+        assert_eq!(parse_ui_amount("5.", 6).unwrap(), 5_000_000);
+        assert_eq!(parse_ui_amount(" 1 ", 9).unwrap(), 1_000_000_000);
+        assert_eq!(parse_ui_amount("0.000001", 6).unwrap(), 1);
+        assert_eq!(parse_ui_amount("007", 2).unwrap(), 700);
+    }
+
+    #[test]
+    fn parses_amounts_of_mints_without_decimals() {
+        assert_eq!(parse_ui_amount("42", 0).unwrap(), 42);
+        assert_eq!(parse_ui_amount("42.", 0).unwrap(), 42);
+        assert!(parse_ui_amount("42.5", 0).is_err());
+        assert!(parse_ui_amount(".5", 0).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid_amounts() {
+        for amount in ["", ".", "abc", "1.2.3", "-1", "+1", "1e3", "1,5", "1 000"] {
+            assert!(parse_ui_amount(amount, 6).is_err(), "{:?}", amount);
+        }
+    }
+
+    #[test]
+    fn rejects_more_decimals_than_the_mint() {
+        let e = parse_ui_amount("1.1234567", 6).unwrap_err();
+        assert!(e.to_string().contains("more than the 6 decimals"), "{}", e);
+        assert_eq!(parse_ui_amount("1.123456", 6).unwrap(), 1_123_456);
+    }
+
+    #[test]
+    fn rejects_zero() {
+        for amount in ["0", "0.0", ".000", "000"] {
+            let e = parse_ui_amount(amount, 6).unwrap_err();
+            assert!(e.to_string().contains("greater than zero"), "{}", e);
+        }
+    }
+
+    #[test]
+    fn rejects_u64_overflow() {
+        assert_eq!(
+            parse_ui_amount("18446744073709551615", 0).unwrap(),
+            u64::MAX
+        );
+        assert_eq!(
+            parse_ui_amount("18446744073.709551615", 9).unwrap(),
+            u64::MAX
+        );
+        for (amount, decimals) in [("18446744073709551616", 0), ("18446744073.709551616", 9)] {
+            let e = parse_ui_amount(amount, decimals).unwrap_err();
+            assert!(e.to_string().contains("does not fit into a u64"), "{}", e);
+        }
+    }
+
+    #[test]
+    fn formats_ui_amounts() {
+        assert_eq!(format_ui_amount(12_500_000, 6), "12.5");
+        assert_eq!(format_ui_amount(1, 6), "0.000001");
+        assert_eq!(format_ui_amount(0, 6), "0");
+        assert_eq!(format_ui_amount(5_000_000, 6), "5");
+        assert_eq!(format_ui_amount(42, 0), "42");
+        assert_eq!(format_ui_amount(u64::MAX, 9), "18446744073.709551615");
+    }
+
+    #[test]
+    fn round_trips() {
+        for decimals in [0u8, 2, 6, 9, 19] {
+            for amount in [1u64, 9, 10, 1_000, 123_456_789, u64::MAX] {
+                let ui_amount = format_ui_amount(amount, decimals);
+                assert_eq!(
+                    parse_ui_amount(&ui_amount, decimals).unwrap(),
+                    amount,
+                    "{} with {} decimals",
+                    ui_amount,
+                    decimals
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,416 @@
+//! Transaction buffers of the multisig program (v2.1), used to create vault transactions whose
+//! message does not fit in a single Solana transaction.
+//!
+//! `squads-multisig` does not expose these instructions yet, so they are built by hand
+//! from `idl.json`.
+
+use std::time::Duration;
+
+use colored::Colorize;
+use eyre::eyre;
+use indicatif::{ProgressBar, ProgressStyle};
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::hash::hash;
+use solana_sdk::instruction::{AccountMeta, Instruction};
+use solana_sdk::message::VersionedMessage;
+use solana_sdk::message::v0::Message;
+use solana_sdk::packet::PACKET_DATA_SIZE;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signer::Signer;
+use solana_sdk::system_program;
+use solana_sdk::transaction::VersionedTransaction;
+use squads_multisig::anchor_lang::AnchorSerialize;
+use squads_multisig::pda::get_transaction_pda;
+use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
+use squads_multisig::squads_multisig_program::VaultTransactionCreateArgs;
+
+use crate::utils::{anchor_discriminator, send_and_confirm_transaction, transaction_details};
+
+/// Largest message the program accepts in a transaction buffer.
+pub const MAX_BUFFER_SIZE: usize = 4000;
+
+/// Bytes of the message uploaded by each create / extend instruction.
+const CHUNK_SIZE: usize = 900;
+
+/// Returns true when `message` signed by `num_signers` does not fit in a single packet.
+pub fn exceeds_transaction_size(message: &VersionedMessage, num_signers: usize) -> bool {
+    // One byte for the signature count, 64 bytes per signature.
+    1 + 64 * num_signers + message.serialize().len() > PACKET_DATA_SIZE
+}
+
+pub fn get_transaction_buffer_pda(
+    multisig: &Pubkey,
+    creator: &Pubkey,
+    buffer_index: u8,
+    program_id: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"multisig",
+            multisig.as_ref(),
+            b"transaction_buffer",
+            creator.as_ref(),
+            &buffer_index.to_le_bytes(),
+        ],
+        program_id,
+    )
+}
+
+fn borsh_bytes(data: &mut Vec<u8>, bytes: &[u8]) {
+    data.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    data.extend_from_slice(bytes);
+}
+
+pub struct TransactionBufferCreateArgs {
+    pub buffer_index: u8,
+    pub vault_index: u8,
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u16,
+    pub buffer: Vec<u8>,
+}
+
+pub fn transaction_buffer_create(
+    multisig: &Pubkey,
+    transaction_buffer: &Pubkey,
+    creator: &Pubkey,
+    args: &TransactionBufferCreateArgs,
+    program_id: &Pubkey,
+) -> Instruction {
+    let mut data = anchor_discriminator("global", "transaction_buffer_create").to_vec();
+    data.push(args.buffer_index);
+    data.push(args.vault_index);
+    data.extend_from_slice(&args.final_buffer_hash);
+    data.extend_from_slice(&args.final_buffer_size.to_le_bytes());
+    borsh_bytes(&mut data, &args.buffer);
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*transaction_buffer, false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+        ],
+        data,
+    }
+}
+
+pub fn transaction_buffer_extend(
+    multisig: &Pubkey,
+    transaction_buffer: &Pubkey,
+    creator: &Pubkey,
+    buffer: &[u8],
+    program_id: &Pubkey,
+) -> Instruction {
+    let mut data = anchor_discriminator("global", "transaction_buffer_extend").to_vec();
+    borsh_bytes(&mut data, buffer);
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*transaction_buffer, false),
+            AccountMeta::new_readonly(*creator, true),
+        ],
+        data,
+    }
+}
+
+pub fn transaction_buffer_close(
+    multisig: &Pubkey,
+    transaction_buffer: &Pubkey,
+    creator: &Pubkey,
+    program_id: &Pubkey,
+) -> Instruction {
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new_readonly(*multisig, false),
+            AccountMeta::new(*transaction_buffer, false),
+            AccountMeta::new_readonly(*creator, true),
+        ],
+        data: anchor_discriminator("global", "transaction_buffer_close").to_vec(),
+    }
+}
+
+/// The buffer is closed by the program once the vault transaction is created from it.
+pub fn vault_transaction_create_from_buffer(
+    multisig: &Pubkey,
+    transaction: &Pubkey,
+    transaction_buffer: &Pubkey,
+    creator: &Pubkey,
+    args: &VaultTransactionCreateArgs,
+    program_id: &Pubkey,
+) -> Instruction {
+    let mut data = anchor_discriminator("global", "vault_transaction_create_from_buffer").to_vec();
+    data.extend(args.try_to_vec().unwrap());
+
+    Instruction {
+        program_id: *program_id,
+        accounts: vec![
+            AccountMeta::new(*multisig, false),
+            AccountMeta::new(*transaction, false),
+            AccountMeta::new_readonly(*creator, true),
+            AccountMeta::new(*creator, true),
+            AccountMeta::new_readonly(system_program::id(), false),
+            AccountMeta::new(*transaction_buffer, false),
+            AccountMeta::new(*creator, true),
+        ],
+        data,
+    }
+}
+
+pub struct TransactionBuffer {
+    pub multisig: Pubkey,
+    pub creator: Pubkey,
+    pub buffer_index: u8,
+    pub vault_index: u8,
+    pub final_buffer_hash: [u8; 32],
+    pub final_buffer_size: u16,
+    pub buffer: Vec<u8>,
+}
+
+impl TransactionBuffer {
+    pub fn try_deserialize(data: &[u8]) -> eyre::Result<Self> {
+        let invalid = || eyre!("Account is not a transaction buffer");
+        if data.len() < 8 || data[..8] != anchor_discriminator("account", "TransactionBuffer") {
+            return Err(invalid());
+        }
+        let data = &data[8..];
+        // multisig (32) + creator (32) + indexes (2) + hash (32) + size (2) + buffer length (4)
+        if data.len() < 104 {
+            return Err(invalid());
+        }
+        let buffer_len = u32::from_le_bytes(data[100..104].try_into().unwrap()) as usize;
+        let buffer = data
+            .get(104..104 + buffer_len)
+            .ok_or_else(invalid)?
+            .to_vec();
+
+        Ok(Self {
+            multisig: Pubkey::try_from(&data[..32]).unwrap(),
+            creator: Pubkey::try_from(&data[32..64]).unwrap(),
+            buffer_index: data[64],
+            vault_index: data[65],
+            final_buffer_hash: data[66..98].try_into().unwrap(),
+            final_buffer_size: u16::from_le_bytes(data[98..100].try_into().unwrap()),
+            buffer,
+        })
+    }
+}
+
+/// A vault transaction whose message is uploaded through a transaction buffer.
+pub struct BufferedVaultTransaction {
+    pub multisig: Pubkey,
+    pub transaction_index: u64,
+    pub vault_index: u8,
+    pub ephemeral_signers: u8,
+    pub memo: Option<String>,
+    /// The serialized `TransactionMessage`.
+    pub transaction_message: Vec<u8>,
+    pub program_id: Pubkey,
+}
+
+impl BufferedVaultTransaction {
+    /// Uploads the message, verifies it on chain and creates the vault transaction from it,
+    /// followed by `trailing_instructions` (usually the proposal creation). Every transaction is
+    /// printed with [`transaction_details`] before it is sent. The buffer is closed again if
+    /// anything fails after it has been created.
+    pub async fn send(
+        self,
+        rpc_client: &RpcClient,
+        creator_keypair: &dyn Signer,
+        trailing_instructions: &[Instruction],
+        priority_fee_lamports: u64,
+    ) -> eyre::Result<String> {
+        let creator = creator_keypair.pubkey();
+        let message_len = self.transaction_message.len();
+        if message_len > MAX_BUFFER_SIZE {
+            return Err(eyre!(
+                "The transaction message is {} bytes, transaction buffers hold at most {}",
+                message_len,
+                MAX_BUFFER_SIZE
+            ));
+        }
+
+        // Leftover buffers of the same creator occupy their index until they are closed.
+        let mut buffer_index = None;
+        for index in 0..=u8::MAX {
+            let pda =
+                get_transaction_buffer_pda(&self.multisig, &creator, index, &self.program_id).0;
+            if rpc_client.get_account(&pda).await.is_err() {
+                buffer_index = Some(index);
+                break;
+            }
+        }
+        let buffer_index =
+            buffer_index.ok_or_else(|| eyre!("All transaction buffer indexes are in use"))?;
+        let transaction_buffer =
+            get_transaction_buffer_pda(&self.multisig, &creator, buffer_index, &self.program_id).0;
+
+        let final_buffer_hash = hash(&self.transaction_message).to_bytes();
+        let chunks: Vec<&[u8]> = self.transaction_message.chunks(CHUNK_SIZE).collect();
+
+        println!(
+            "{}",
+            format!(
+                "ℹ️ The transaction message is {} bytes, uploading it in {} chunks to transaction buffer {}",
+                message_len,
+                chunks.len(),
+                transaction_buffer
+            )
+            .bright_black()
+        );
+
+        let progress = ProgressBar::new(chunks.len() as u64)
+            .with_style(
+                ProgressStyle::with_template("{msg} [{bar:30}] {pos}/{len}")
+                    .unwrap()
+                    .progress_chars("=> "),
+            )
+            .with_message("Uploading transaction message");
+
+        let mut instructions = vec![transaction_buffer_create(
+            &self.multisig,
+            &transaction_buffer,
+            &creator,
+            &TransactionBufferCreateArgs {
+                buffer_index,
+                vault_index: self.vault_index,
+                final_buffer_hash,
+                final_buffer_size: message_len as u16,
+                buffer: chunks[0].to_vec(),
+            },
+            &self.program_id,
+        )];
+        send_instructions(
+            rpc_client,
+            creator_keypair,
+            &instructions,
+            priority_fee_lamports,
+        )
+        .await?;
+        progress.inc(1);
+
+        let result = async {
+            for chunk in &chunks[1..] {
+                instructions = vec![transaction_buffer_extend(
+                    &self.multisig,
+                    &transaction_buffer,
+                    &creator,
+                    chunk,
+                    &self.program_id,
+                )];
+                send_instructions(
+                    rpc_client,
+                    creator_keypair,
+                    &instructions,
+                    priority_fee_lamports,
+                )
+                .await?;
+                progress.inc(1);
+            }
+            progress.finish();
+
+            let account = rpc_client.get_account(&transaction_buffer).await?;
+            let uploaded = TransactionBuffer::try_deserialize(&account.data)?;
+            if uploaded.multisig != self.multisig
+                || uploaded.creator != creator
+                || uploaded.buffer_index != buffer_index
+                || uploaded.vault_index != self.vault_index
+                || uploaded.final_buffer_size as usize != message_len
+                || uploaded.final_buffer_hash != final_buffer_hash
+                || uploaded.buffer != self.transaction_message
+                || hash(&uploaded.buffer).to_bytes() != uploaded.final_buffer_hash
+            {
+                return Err(eyre!(
+                    "SECURITY WARNING: the uploaded buffer does not match the transaction message"
+                ));
+            }
+            println!(
+                "{}",
+                format!(
+                    "✅ Buffer verified, hash {}",
+                    solana_sdk::hash::Hash::new_from_array(final_buffer_hash)
+                )
+                .green()
+            );
+
+            let transaction_pda = get_transaction_pda(
+                &self.multisig,
+                self.transaction_index,
+                Some(&self.program_id),
+            );
+            let mut instructions = vec![
+                ComputeBudgetInstruction::set_compute_unit_price(priority_fee_lamports),
+                vault_transaction_create_from_buffer(
+                    &self.multisig,
+                    &transaction_pda.0,
+                    &transaction_buffer,
+                    &creator,
+                    &VaultTransactionCreateArgs {
+                        vault_index: self.vault_index,
+                        ephemeral_signers: self.ephemeral_signers,
+                        // Must be an empty message, the program reads the real one from the buffer.
+                        transaction_message: vec![0, 0, 0, 0, 0, 0],
+                        memo: self.memo.clone(),
+                    },
+                    &self.program_id,
+                ),
+            ];
+            instructions.extend_from_slice(trailing_instructions);
+
+            let spinner = ProgressBar::new_spinner().with_message("Sending transaction...");
+            spinner.enable_steady_tick(Duration::from_millis(100));
+            let blockhash = rpc_client.get_latest_blockhash().await?;
+            let message = Message::try_compile(&creator, &instructions, &[], blockhash)?;
+            let transaction =
+                VersionedTransaction::try_new(VersionedMessage::V0(message), &[creator_keypair])?;
+            if let Err(e) = transaction_details(&transaction) {
+                eprintln!("❌ Error processing transaction details: {}", e);
+            }
+            send_and_confirm_transaction(&transaction, rpc_client).await
+        }
+        .await;
+
+        if result.is_err() {
+            progress.abandon();
+            eprintln!(
+                "{}",
+                format!("Closing transaction buffer {}...", transaction_buffer).yellow()
+            );
+            let close = [transaction_buffer_close(
+                &self.multisig,
+                &transaction_buffer,
+                &creator,
+                &self.program_id,
+            )];
+            if let Err(e) =
+                send_instructions(rpc_client, creator_keypair, &close, priority_fee_lamports).await
+            {
+                eprintln!("❌ Failed to close transaction buffer: {}", e);
+            }
+        }
+        result
+    }
+}
+
+async fn send_instructions(
+    rpc_client: &RpcClient,
+    signer: &dyn Signer,
+    instructions: &[Instruction],
+    priority_fee_lamports: u64,
+) -> eyre::Result<String> {
+    let mut all_instructions = vec![ComputeBudgetInstruction::set_compute_unit_price(
+        priority_fee_lamports,
+    )];
+    all_instructions.extend_from_slice(instructions);
+    let blockhash = rpc_client.get_latest_blockhash().await?;
+    let message = Message::try_compile(&signer.pubkey(), &all_instructions, &[], blockhash)?;
+    let transaction = VersionedTransaction::try_new(VersionedMessage::V0(message), &[signer])?;
+    if let Err(e) = transaction_details(&transaction) {
+        eprintln!("❌ Error processing transaction details: {}", e);
+    }
+    send_and_confirm_transaction(&transaction, rpc_client).await
+}
@@ -1,17 +1,26 @@
-use crate::squads_decoder::{ParseableInstruction, map_instruction};
+use crate::address_book;
+use crate::output;
+use crate::squads_decoder::{
+    IdlRegistry, IdlSource, InstructionMapResult, ParseableInstruction, PdaCheck, PdaStatus,
+    idl_registry, map_instruction,
+};
+use crate::token_amount::{cached_mint_info, fetch_mint_info};
 use clap_v3::ArgMatches;
 use colored::Colorize;
 use eyre::eyre;
-use serde_json::{Map, Value};
+use serde_json::{Map, Value, json};
 use solana_clap_v3_utils::keypair::signer_from_path;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::{signer::Signer, transaction::VersionedTransaction};
+use squads_multisig::anchor_lang::AnchorDeserialize;
 use squads_multisig::solana_client::nonblocking::rpc_client::RpcClient;
 use squads_multisig::solana_client::{
     client_error::ClientErrorKind,
     rpc_request::{RpcError, RpcResponseErrorData},
     rpc_response::RpcSimulateTransactionResult,
 };
+use squads_multisig::squads_multisig_program::ConfigTransactionCreateArgs;
+use squads_multisig::state::{ConfigAction, Member, Multisig, Period, Permission, Permissions};
 use std::str::FromStr;
 
 pub fn extract_transaction_message(json_str: &str) -> Result<Vec<u8>, String> {
@@ -61,6 +70,7 @@ pub async fn send_and_confirm_transaction(
                 "Transaction confirmed: {}\n\n",
                 signature.to_string().green()
             );
+            output::push("signatures", json!(signature.to_string()));
             Ok(signature.to_string())
         }
         Err(err) => {
@@ -75,6 +85,7 @@ pub async fn send_and_confirm_transaction(
             }) = &err.kind
             {
                 println!("Simulation logs:\n\n{}\n", logs.join("\n").yellow());
+                output::set("simulation_logs", json!(logs));
             }
 
             Err(eyre!("Transaction failed: {}", err.to_string().red()))
@@ -85,6 +96,7 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
     let squads_program_id = Pubkey::from_str("SQDS4ep65T869zMMBKyuUq6aD6EgTu8psMjkvj52pCf")?;
     let message = &transaction.message;
     let mut result = Value::Null;
+    let mut squads_instructions = Vec::new();
 
     // Classify and print account roles with security context
     let accounts: Vec<String> = message
@@ -119,16 +131,25 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
     for account in &readonly_unsigned {
         println!("    - {}", account.green());
     }
+    let mut document = json!({
+        "signatures": transaction.signatures.iter().map(|s| s.to_string()).collect::<Vec<_>>(),
+        "account_roles": {
+            "mutable_signers": mutable_signers,
+            "readonly_signers": readonly_signers,
+            "mutable_unsigned": mutable_unsigned,
+            "readonly_unsigned": readonly_unsigned,
+        },
+    });
 
-    // Load IDL for Squads program
-    let idl_path = std::path::Path::new("./idl.json");
-    if !idl_path.exists() {
-        return Err(eyre::eyre!(
-            "SECURITY WARNING: Missing IDL file at {:?}",
-            idl_path
-        ));
-    }
-    let idl = solana_idl::try_extract_classic_idl(std::fs::read_to_string(idl_path)?.as_str())?;
+    // Squads deployments other than the canonical one decode with the builtin Squads IDL.
+    let global_registry = idl_registry();
+    let local_registry;
+    let registry = if global_registry.get(&squads_program_id).is_some() {
+        &*global_registry
+    } else {
+        local_registry = IdlRegistry::with_squads_idl(squads_program_id);
+        &local_registry
+    };
 
     // Process all instructions targeting Squads program
     println!("\n🔍 INSPECTING SQUADS INSTRUCTIONS:");
@@ -159,7 +180,7 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
         // Map and print instruction details with security focus
         match map_instruction(
             &instruction,
-            Some(&idl),
+            registry,
             &chainparser::borsh::BorshDeserializer,
         ) {
             Ok(ix_map_result) => {
@@ -167,13 +188,17 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
                     "  📛 Instruction: {}",
                     ix_map_result
                         .instruction_name
+                        .clone()
                         .unwrap_or("UNKNOWN".to_string())
                         .red()
                         .bold()
                 );
 
+                print_idl_source(ix_map_result.idl_source);
+
                 println!("  🔑 Accounts Involved:");
-                for (pubkey, label) in ix_map_result.accounts {
+                let mut accounts = Vec::new();
+                for (pubkey, label) in &ix_map_result.accounts {
                     let role = if mutable_signers.contains(&pubkey.to_string()) {
                         "MUTABLE SIGNER".red()
                     } else if readonly_signers.contains(&pubkey.to_string()) {
@@ -183,7 +208,23 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
                     } else {
                         "READONLY UNSIGNED".green()
                     };
-                    println!("    - {}: {} ({})", pubkey, label, role);
+                    let known_as = address_book::label(pubkey);
+                    match &known_as {
+                        Some(known_as) => println!(
+                            "    - {}: {} ({}) 📒 {}",
+                            pubkey,
+                            label,
+                            role,
+                            known_as.bright_cyan()
+                        ),
+                        None => println!("    - {}: {} ({})", pubkey, label, role),
+                    }
+                    accounts.push(json!({
+                        "pubkey": pubkey.to_string(),
+                        "label": label,
+                        "known_as": known_as,
+                        "role": role.input,
+                    }));
                 }
 
                 //  let redacted_args = redact_sensitive_data(&ix_map_result.decoded_args);
@@ -195,6 +236,29 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
                         .bright_black()
                 );
 
+                let pda_checks = print_pda_checks(&ix_map_result.pda_checks);
+                print_decode_warnings(&ix_map_result);
+
+                let config_actions = if ix_map_result.instruction_name.as_deref()
+                    == Some("configTransactionCreate")
+                {
+                    print_config_actions(&ix.data)
+                } else {
+                    None
+                };
+                squads_instructions.push(json!({
+                    "index": ix_index,
+                    "program_id": program_pubkey.to_string(),
+                    "idl_source": ix_map_result.idl_source.describe(),
+                    "instruction": ix_map_result.instruction_name,
+                    "accounts": accounts,
+                    "args": ix_map_result.decoded_args.clone(),
+                    "match": ix_map_result.confidence.to_string(),
+                    "trailing_data": hex::encode(&ix_map_result.trailing_data),
+                    "pda_checks": pda_checks,
+                    "config_actions": config_actions,
+                }));
+
                 // Store first Squads instruction result
                 if result.is_null() {
                     result = ix_map_result.decoded_args;
@@ -206,13 +270,106 @@ pub fn transaction_details(transaction: &VersionedTransaction) -> eyre::Result<V
                     ix_index + 1,
                     e
                 );
+                output::error(format!(
+                    "Failed to decode Squads instruction #{}: {}",
+                    ix_index + 1,
+                    e
+                ));
             }
         }
     }
 
+    document["squads_instructions"] = Value::Array(squads_instructions);
+    output::push("transactions", document);
     Ok(result)
 }
 
+/// Prints the warnings about how the instruction was matched and decoded, and adds them to the
+/// output warnings.
+/// Prints where the IDL a decode relies on came from, an on-chain IDL is only as trustworthy as the
+/// program publishing it.
+pub fn print_idl_source(source: IdlSource) {
+    if source.is_trusted() {
+        println!("  {}", format!("IDL: {}", source.describe()).bright_black());
+    } else {
+        println!("  {}", format!("⚠️ IDL: {}", source.describe()).yellow());
+    }
+}
+
+pub fn print_decode_warnings(decoded: &InstructionMapResult) {
+    for warning in decoded.warnings() {
+        println!("  {}", format!("⚠️ {}", warning).yellow());
+        output::push("warnings", json!(warning));
+    }
+}
+
+/// Prints whether the accounts the IDL describes as PDAs are the ones its seeds derive, a mismatch
+/// means the instruction operates on a different account than the program expects.
+pub fn print_pda_checks(checks: &[PdaCheck]) -> Value {
+    let mut documents = Vec::new();
+    for check in checks {
+        let status = match &check.status {
+            PdaStatus::Verified => {
+                println!(
+                    "  {}",
+                    format!("✅ PDA verified: {} {}", check.account, check.pubkey).green()
+                );
+                json!({"status": "verified"})
+            }
+            PdaStatus::Mismatch { expected } => {
+                let warning = format!(
+                    "{} {} is not the PDA its seeds derive ({})",
+                    check.account, check.pubkey, expected
+                );
+                println!(
+                    "  {}",
+                    format!("❌ SECURITY WARNING: {}", warning).red().bold()
+                );
+                output::push("warnings", json!(warning));
+                json!({"status": "mismatch", "expected": expected.to_string()})
+            }
+            PdaStatus::Unverifiable(reason) => {
+                println!(
+                    "  {}",
+                    format!("⚠️ PDA not verified: {} ({})", check.account, reason).yellow()
+                );
+                json!({"status": "unverifiable", "reason": reason})
+            }
+        };
+        let mut document = json!({
+            "account": check.account,
+            "pubkey": check.pubkey.to_string(),
+        });
+        if let (Value::Object(document), Value::Object(status)) = (&mut document, status) {
+            document.extend(status);
+        }
+        documents.push(document);
+    }
+    Value::Array(documents)
+}
+
+fn print_config_actions(data: &[u8]) -> Option<Vec<String>> {
+    // Skip the 8 byte anchor discriminator, the args follow directly.
+    match data
+        .get(8..)
+        .map(|mut args| ConfigTransactionCreateArgs::deserialize(&mut args))
+    {
+        Some(Ok(args)) => {
+            println!("  🛠️ Config Actions:");
+            let actions: Vec<String> = args.actions.iter().map(describe_config_action).collect();
+            for (i, action) in actions.iter().enumerate() {
+                println!("    {}. {}", i + 1, action.bright_cyan());
+            }
+            Some(actions)
+        }
+        _ => {
+            eprintln!("❌ SECURITY ALERT: Failed to decode config actions");
+            output::error("Failed to decode config actions");
+            None
+        }
+    }
+}
+
 fn classify_accounts(
     accounts: Vec<String>,
     num_required_signatures: usize,
@@ -283,3 +440,187 @@ impl ParseableInstruction for MyInstruction {
         &self.instruction_data
     }
 }
+
+pub fn parse_members(member_strings: Vec<String>) -> Result<Vec<Member>, String> {
+    member_strings
+        .into_iter()
+        .map(|s| {
+            let parts: Vec<&str> = s.split(',').collect();
+            if parts.len() != 2 {
+                return Err(
+                    "Each entry must be in the format <public_key>,<permission>".to_string()
+                );
+            }
+
+            let key =
+                Pubkey::from_str(parts[0]).map_err(|_| "Invalid public key format".to_string())?;
+            let permissions = parts[1]
+                .parse::<u8>()
+                .map_err(|_| "Invalid permission format".to_string())?;
+
+            Ok(Member {
+                key,
+                permissions: Permissions { mask: permissions },
+            })
+        })
+        .collect()
+}
+
+pub fn parse_period(period: &str) -> Result<Period, String> {
+    match period.to_lowercase().as_str() {
+        "onetime" | "one-time" | "once" => Ok(Period::OneTime),
+        "day" | "daily" => Ok(Period::Day),
+        "week" | "weekly" => Ok(Period::Week),
+        "month" | "monthly" => Ok(Period::Month),
+        _ => Err(format!(
+            "Invalid period '{}'. Please use one of: OneTime, Day, Week, Month",
+            period
+        )),
+    }
+}
+
+/// Renders a permission mask as `Initiate+Vote+Execute`, keeping unknown bits visible.
+pub fn format_permissions(permissions: &Permissions) -> String {
+    let mut names = Vec::new();
+    if permissions.has(Permission::Initiate) {
+        names.push("Initiate".to_string());
+    }
+    if permissions.has(Permission::Vote) {
+        names.push("Vote".to_string());
+    }
+    if permissions.has(Permission::Execute) {
+        names.push("Execute".to_string());
+    }
+    let unknown_bits = permissions.mask & !0b111;
+    if unknown_bits != 0 {
+        names.push(format!("Unknown({:#010b})", unknown_bits));
+    }
+    if names.is_empty() {
+        "None".to_string()
+    } else {
+        names.join("+")
+    }
+}
+
+/// Fetches the mints of the spending limits `actions` add, so [`describe_config_action`] can show
+/// their amounts with decimals and symbol. Mints that cannot be loaded fall back to base units.
+pub async fn load_config_action_mints(rpc_client: &RpcClient, actions: &[ConfigAction]) {
+    for action in actions {
+        if let ConfigAction::AddSpendingLimit { mint, .. } = action
+            && let Err(e) = fetch_mint_info(rpc_client, mint).await
+        {
+            let warning = format!(
+                "Decimals of spending limit mint {} not loaded, the amount is in base units: {}",
+                mint, e
+            );
+            println!("{}", format!("⚠️ {}", warning).yellow());
+            output::push("warnings", json!(warning));
+        }
+    }
+}
+
+/// Human readable one-liner for a config action, used on every review screen.
+pub fn describe_config_action(action: &ConfigAction) -> String {
+    match action {
+        ConfigAction::AddMember { new_member } => format!(
+            "Add member {} with {}",
+            new_member.key,
+            format_permissions(&new_member.permissions)
+        ),
+        ConfigAction::RemoveMember { old_member } => format!("Remove member {}", old_member),
+        ConfigAction::ChangeThreshold { new_threshold } => {
+            format!("Change threshold to {}", new_threshold)
+        }
+        ConfigAction::SetTimeLock { new_time_lock } => {
+            format!("Set time lock to {} seconds", new_time_lock)
+        }
+        ConfigAction::AddSpendingLimit {
+            create_key,
+            vault_index,
+            mint,
+            amount,
+            period,
+            members,
+            destinations,
+        } => format!(
+            "Add spending limit (create key {}) of {} per {:?} from vault {}, members: [{}], destinations: [{}]",
+            create_key,
+            match cached_mint_info(mint) {
+                Some(mint_info) => format!("{} of mint {}", mint_info.display(*amount), mint),
+                None => format!("{} base units of mint {}", amount, mint),
+            },
+            period,
+            vault_index,
+            join_pubkeys(members),
+            if destinations.is_empty() {
+                "any".to_string()
+            } else {
+                join_pubkeys(destinations)
+            }
+        ),
+        ConfigAction::RemoveSpendingLimit { spending_limit } => {
+            format!("Remove spending limit {}", spending_limit)
+        }
+        ConfigAction::SetRentCollector { new_rent_collector } => format!(
+            "Set rent collector to {}",
+            new_rent_collector
+                .map(|k| k.to_string())
+                .unwrap_or_else(|| "None".to_string())
+        ),
+        _ => "Unknown config action".to_string(),
+    }
+}
+
+fn join_pubkeys(keys: &[Pubkey]) -> String {
+    keys.iter()
+        .map(|k| k.to_string())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Direct `multisig*` instructions are only accepted from the multisig's config authority.
+pub fn ensure_config_authority(multisig: &Multisig, signer: &Pubkey) -> eyre::Result<()> {
+    if multisig.config_authority == Pubkey::default() {
+        return Err(eyre!(
+            "The multisig is autonomous (no config authority), use config-transaction-create instead"
+        ));
+    }
+    if multisig.config_authority != *signer {
+        return Err(eyre!(
+            "Signer {} is not the config authority {} of the multisig",
+            signer,
+            multisig.config_authority
+        ));
+    }
+    Ok(())
+}
+
+/// `squads_multisig::pda` has no helper for the transactions stored inside a batch.
+pub fn get_batch_transaction_pda(
+    multisig: &Pubkey,
+    batch_index: u64,
+    transaction_index: u32,
+    program_id: Option<&Pubkey>,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"multisig",
+            multisig.as_ref(),
+            b"transaction",
+            &batch_index.to_le_bytes(),
+            b"batch_transaction",
+            &transaction_index.to_le_bytes(),
+        ],
+        program_id.unwrap_or(&squads_multisig::squads_multisig_program::ID),
+    )
+}
+
+/// Replicates the anchor discriminators, `namespace` is `global` for instructions and `account`
+/// for accounts.
+pub fn anchor_discriminator(namespace: &str, name: &str) -> [u8; 8] {
+    let mut discriminator = [0u8; 8];
+    discriminator.copy_from_slice(
+        &solana_sdk::hash::hash(format!("{namespace}:{name}").as_bytes()).to_bytes()[..8],
+    );
+    discriminator
+}